@@ -0,0 +1,54 @@
+/// Generates the solution registry, see `src/template/registry.rs`.
+/// Every `src/bin/<year>-<day>.rs` module is included into the library so that all days can run in one process.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let mut puzzles: Vec<String> = fs::read_dir("src/bin")
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter_map(|name| name.strip_suffix(".rs").map(String::from))
+                .filter(|name| is_puzzle_name(name))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    puzzles.sort_unstable();
+
+    let mut registry = String::new();
+
+    for puzzle in &puzzles {
+        let module = puzzle.replace('-', "_");
+        registry.push_str(&format!(
+            "#[allow(dead_code, unused_imports)]\n\
+             mod day{module} {{\n    \
+                 use super::shim as advent_of_code;\n    \
+                 include!(concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/src/bin/{puzzle}.rs\"));\n\
+             }}\n\n"
+        ));
+    }
+
+    registry.push_str("static SOLUTIONS: &[Solution] = &[\n");
+    for puzzle in &puzzles {
+        let module = puzzle.replace('-', "_");
+        registry.push_str(&format!("    day{module}::SOLUTION,\n"));
+    }
+    registry.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry).unwrap();
+}
+
+/// Solution modules are named `<year>-<day>`, e.g. `2023-01`.
+fn is_puzzle_name(name: &str) -> bool {
+    match name.split_once('-') {
+        Some((year, day)) => {
+            year.len() == 4
+                && day.len() == 2
+                && year.chars().chain(day.chars()).all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(1);
+advent_of_code::solution!(2023, 1);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     Some(
@@ -57,25 +57,3 @@ pub fn part_two(_input: &str) -> Option<u64> {
         .sum::<u32>() as u64
     )
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        // let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        let s = "1abc2
-        pqr3stu8vwx
-        a1b2c3d4e5f
-        treb7uchet";
-        let result = part_one(s);
-        assert_eq!(result, Some(142));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(281));
-    }
-}
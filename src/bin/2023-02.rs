@@ -1,4 +1,4 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     Some(
@@ -65,20 +65,3 @@ pub fn part_two(_input: &str) -> Option<u64> {
     )
     // None
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2286));
-    }
-}
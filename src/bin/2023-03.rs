@@ -1,6 +1,6 @@
 use std::{collections::HashMap, ops::Range};
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2023, 3);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     let (numbers, symbols) = parse_numbers_and_symbols(_input);
@@ -18,23 +18,6 @@ pub fn part_two(_input: &str) -> Option<u64> {
     Some(product as u64)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4361));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(467835));
-    }
-}
-
 #[derive(PartialEq, Eq, Clone, Debug)]
 struct Number {
     line: usize,
@@ -1,5 +1,5 @@
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     let r = _input
@@ -53,8 +53,8 @@ pub fn part_two(_input: &str) -> Option<u64> {
         let holding = holding.split_whitespace().collect::<Vec<_>>();
         let num_winners = winning.filter(|s|holding.contains(s)).count();
         let num_cards = counts[idx];
-        for i in idx+1..=idx+num_winners {
-            counts[i] += num_cards;
+        for count in &mut counts[idx+1..=idx+num_winners] {
+            *count += num_cards;
         }
         Some(num_cards)
 }).sum())
@@ -111,20 +111,3 @@ pub fn part_two(_input: &str) -> Option<u64> {
     // }
     // Some(stack.len() as u64)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(30));
-    }
-}
@@ -1,6 +1,8 @@
 use std::ops::Range;
 
-advent_of_code::solution!(5);
+use advent_of_code::ranges::{ intersect, subtract, RangeSet };
+
+advent_of_code::solution!(2023, 5);
 
 pub fn part_one(_input: &str) -> Option<i64> {
     let almanac = Almanac::parse(_input);
@@ -16,32 +18,23 @@ pub fn part_one(_input: &str) -> Option<i64> {
 
 pub fn part_two(_input: &str) -> Option<i64> {
     let almanac = Almanac::parse(_input);
-    // let almanac = Almanac::parse(&advent_of_code::template::read_file("examples", DAY));
+    // let almanac = Almanac::parse(&advent_of_code::template::read_file("examples", PUZZLE));
     let mut seeds = almanac.seeds
         .clone()
         .chunks_exact(2)
         .map(|chunk| chunk[0]..chunk[0] + chunk[1])
-        .collect::<Vec<Range<i64>>>();
+        .collect::<RangeSet<i64>>();
 
     // dbg!(&seeds);
     for index in 0..almanac.maps.len() {
         seeds = seeds
-            .iter()
-            .map(|range| almanac.apply_range(range.clone(), index))
-            .flatten()
+            .into_iter()
+            .flat_map(|range| almanac.apply_range(range, index))
             .collect();
-        seeds = merge_ranges(seeds);
-        seeds.sort_by_key(|r| r.start);
         // println!("maps: {:?}", almanac.maps[index]);
         // dbg!(&seeds);
     }
-    Some(
-        seeds
-            .iter()
-            .map(|r| r.start)
-            .min()
-            .unwrap()
-    )
+    seeds.min()
     // None
 }
 
@@ -57,7 +50,7 @@ impl IntoIterator for RangeMap {
     type IntoIter = std::ops::Range<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.range.into_iter()
+        self.range
     }
 }
 
@@ -88,7 +81,7 @@ impl RangeMap {
     }
 
     fn overlaps(&self, range: &Range<i64>) -> bool {
-        !(range.end <= self.range.start || range.start >= self.range.end)
+        intersect(&self.range, range).is_some()
     }
 
     fn overlap_split(&self, range: &Range<i64>) -> Range<i64> {
@@ -135,7 +128,7 @@ impl Almanac {
                 let mut maps = map
                     .lines()
                     .skip(1)
-                    .map(|line| RangeMap::from_str(line))
+                    .map(RangeMap::from_str)
                     .collect::<Vec<_>>();
                 maps.sort_by_key(|r| r.range.start);
                 maps
@@ -206,13 +199,13 @@ impl Almanac {
             .filter(|&rule| rule.overlaps(&range))
             .collect::<Vec<_>>();
 
-        if overlapping.len() == 0 {
+        if overlapping.is_empty() {
             vec![range]
         } else {
             let mut next_ranges = Vec::new();
             for rule in overlapping {
                 let new_range = rule.overlap_split(&range);
-                let diff = subtract_range_from(&range, &new_range);
+                let diff = subtract(&range, &new_range);
                 let shifted_range = rule.shift(&new_range);
                 if shifted_range.start < shifted_range.end {
                     next_ranges.push(shifted_range);
@@ -258,55 +251,10 @@ impl Almanac {
     }
 }
 
-fn subtract_range_from(
-    this: &Range<i64>,
-    other: &Range<i64>
-) -> (Option<Range<i64>>, Option<Range<i64>>) {
-    let mut result = (None, None);
-    if this.start < other.start {
-        result.0 = Some(this.start..other.start);
-    }
-    if this.end > other.end {
-        result.1 = Some(other.end..this.end);
-    }
-    result
-}
-
-fn merge_ranges(ranges: Vec<Range<i64>>) -> Vec<Range<i64>> {
-    let mut sorted_ranges = ranges;
-    sorted_ranges.sort_by_key(|r| r.start);
-
-    let mut merged: Vec<Range<i64>> = Vec::new();
-    for range in sorted_ranges {
-        if let Some(last) = merged.last_mut() {
-            if last.end >= range.start {
-                last.end = last.end.max(range.end);
-            } else {
-                merged.push(range);
-            }
-        } else {
-            merged.push(range);
-        }
-    }
-    merged
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(35));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(46));
-    }
-
     #[test]
     fn test_almanac_parse() {
         let input = "seeds: 1 2 3 4\n\nmap1\n10 5 10\n\nmap2\n20 15 5";
@@ -363,23 +311,6 @@ mod tests {
         assert_eq!(shifted_range, 10..15);
     }
 
-    #[test]
-    fn test_merge_ranges() {
-        let ranges = vec![1..3, 2..5, 6..8];
-        let merged = merge_ranges(ranges);
-        assert_eq!(merged, vec![1..5, 6..8]);
-    }
-
-    #[test]
-    fn test_subtract_range_from() {
-        let range = 1..10;
-        let other = 3..7;
-        let result = subtract_range_from(&range, &other);
-        assert_eq!(result, (Some(1..3), Some(7..10)));
-        assert_eq!(subtract_range_from(&(10..20), &(15..25)), (Some(10..15), None));
-        assert_eq!(subtract_range_from(&(10..20), &(5..15)), (None, Some(15..20)));
-    }
-
     #[test]
     fn test_almanac_apply_map() {
         let input = "seeds: 1 2 3\n\nmap1\n10 5 10";
@@ -396,30 +327,30 @@ mod tests {
         let result = almanac.apply_range(range, 0);
         assert_eq!(result, vec![11..13]);
 
-        let input = &advent_of_code::template::read_file("examples", DAY);
+        let input = &advent_of_code::template::read_file("examples", PUZZLE);
         let almanac = Almanac::parse(input);
-        let seeds = vec![55..68, 79..93];
+        let seeds = [55..68, 79..93];
         // maps: [RangeMap { to: 52, start: 50, length: 48 }, RangeMap { to: 50, start: 98, length: 2 }]
         let result = almanac.apply_range(seeds[0].clone(), 0);
-        let result = merge_ranges(result);
+        let result = RangeSet::from_iter(result).into_iter().collect::<Vec<_>>();
         assert_eq!(result, vec![57..70]);
         let result = almanac.apply_range(seeds[1].clone(), 0);
-        let result = merge_ranges(result);
+        let result = RangeSet::from_iter(result).into_iter().collect::<Vec<_>>();
         assert_eq!(result, vec![81..95]);
 
         // maps: [RangeMap { to: 39, start: 0, length: 15 }, RangeMap { to: 0, start: 15, length: 37 }, RangeMap { to: 37, start: 52, length: 2 }]
-        let seeds = vec![81..95, 57..70];
+        let seeds = [81..95, 57..70];
         let result = almanac.apply_range(seeds[0].clone(), 1);
-        let result = merge_ranges(result);
+        let result = RangeSet::from_iter(result).into_iter().collect::<Vec<_>>();
         assert_eq!(result, vec![81..95]);
         let result = almanac.apply_range(seeds[1].clone(), 1);
-        let result = merge_ranges(result);
+        let result = RangeSet::from_iter(result).into_iter().collect::<Vec<_>>();
         assert_eq!(result, vec![57..70]);
     }
 
     // #[test]
     // fn test_apply_range_custom() {
-    //     let input = &advent_of_code::template::read_file("examples", DAY);
+    //     let input = &advent_of_code::template::read_file("examples", PUZZLE);
     //     let almanac = Almanac::parse(input);
     //     let result = almanac.apply_range(55..68, 0);
     //     assert_eq!(result, vec![57..70]);
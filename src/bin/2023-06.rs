@@ -1,11 +1,11 @@
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     Some(
         _input
             .split_once('\n')
             .iter()
-            .map(|(one, two)| {
+            .flat_map(|(one, two)| {
                 one.split_whitespace()
                     .skip(1)
                     .map(|s| s.parse::<u64>().ok().unwrap())
@@ -16,7 +16,6 @@ pub fn part_one(_input: &str) -> Option<u64> {
                             .map(|s| s.parse::<u64>().ok().unwrap())
                     )
             })
-            .flatten()
             .map(|(time, distance)| {
                 let mut charge = 0;
                 let mut wins = 0;
@@ -66,20 +65,3 @@ pub fn part_two(_input: &str) -> Option<u64> {
             }).fold(0, |_, r| r)
     )
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(288));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(71503));
-    }
-}
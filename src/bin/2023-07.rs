@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     let mut result = parse_card_hands(_input);
@@ -124,23 +124,6 @@ pub fn part_two(_input: &str) -> Option<u64> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6440));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5905));
-    }
-}
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum HandType {
     Null = 0,
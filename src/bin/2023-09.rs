@@ -1,4 +1,4 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(2023, 9);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     let mut oasis = Oasis::parse(_input);
@@ -12,23 +12,6 @@ pub fn part_two(_input: &str) -> Option<u64> {
     Some(sum as u64)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(114));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
 enum Part {
     One,
@@ -143,8 +126,8 @@ impl Oasis {
             .histories
             .iter_mut()
             .map(|history| {
-                let p = history.evaluate(part);
-                p
+                
+                history.evaluate(part)
             })
             .sum();
         sum
@@ -1,4 +1,6 @@
-advent_of_code::solution!(10);
+use advent_of_code::{ geometry::interior_points, grid::Point };
+
+advent_of_code::solution!(2023, 10);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     let grid = parse_grid(_input);
@@ -10,39 +12,14 @@ pub fn part_two(_input: &str) -> Option<u64> {
     let grid = parse_grid(_input);
     let loop_list = find_loop(&grid);
 
-    //shoelace formula
-    let mut area: isize = 0;
-    let n = loop_list.len() as isize;
-    for w in loop_list.windows(2) {
-        area += (w[0].row * w[1].col) as isize;
-        area -= (w[0].col * w[1].row) as isize;
-    }
-    let area = isize::abs(area) / 2;
+    // the loop ends back at the start, which the polygon leaves implied
+    let vertices = loop_list[..loop_list.len() - 1]
+        .iter()
+        .map(|coord| Point::new(coord.col as i64, coord.row as i64))
+        .collect::<Vec<_>>();
 
-    //find number of tiles inside
-    Some((area - (n / 2) + 1) as u64  )
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = "..F7.
-.FJ|.
-SJ.L7
-|F--J
-LJ...";
-        let result = part_one(input);
-        assert_eq!(result, Some(8));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(10));
-    }
+    //find number of tiles inside, with the shoelace formula and Pick's theorem
+    Some(interior_points(&vertices))
 }
 
 
@@ -141,7 +118,7 @@ fn parse_grid(input: &str) -> Vec<Vec<Directions>> {
     grid
 }
 
-fn determine_start_coord_shape(grid: &Vec<Vec<Directions>>) -> (Coord, Directions) {
+fn determine_start_coord_shape(grid: &[Vec<Directions>]) -> (Coord, Directions) {
     // locate the start coord
     let mut start_pos: Coord = Coord { row: 0, col: 0 };
     'row: for (row, full_row) in grid.iter().enumerate() {
@@ -153,11 +130,7 @@ fn determine_start_coord_shape(grid: &Vec<Vec<Directions>>) -> (Coord, Direction
         }
     }
     let grid_direction = |c: Option<Coord>| -> Option<Directions> {
-        if let Some(coord) = c {
-            Some(grid[coord.row][coord.col])
-        } else {
-            None
-        }
+        c.map(|coord| grid[coord.row][coord.col])
     };
     // look over the neighboring coordinates
     // prettier-ignore
@@ -184,7 +157,7 @@ fn determine_start_coord_shape(grid: &Vec<Vec<Directions>>) -> (Coord, Direction
     }
 }
 
-fn find_loop(grid: &Vec<Vec<Directions>>) -> Vec<Coord> {
+fn find_loop(grid: &[Vec<Directions>]) -> Vec<Coord> {
     let (start_coord, start_shape) = determine_start_coord_shape(grid);
     let mut loop_list: Vec<Coord> = vec![start_coord];
     let (mut next, mut coming_from) = match start_shape {
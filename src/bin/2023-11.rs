@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(11);
+use advent_of_code::template::params::param;
+
+advent_of_code::solution!(2023, 11);
 
 pub fn part_one(_input: &str) -> Option<u64> {
     let mut universe = Universe::parse(_input, 1);
@@ -9,32 +11,14 @@ pub fn part_one(_input: &str) -> Option<u64> {
     Some(res as u64)
 }
 
-pub fn part_two(_input: &str) -> Option<u64> {
-    let mut universe = Universe::parse(_input, 999_999);
+pub fn part_two(_input: &str) -> Result<u64, String> {
+    // every empty row and column is replaced by this many of them.
+    let expansion: usize = param("expansion", 1_000_000)?;
+    let added = expansion.checked_sub(1).ok_or("expansion must be at least 1")?;
+    let mut universe = Universe::parse(_input, added);
     universe.expand();
     let res = universe.count_shortest_paths();
-    Some(res as u64)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(374));
-    }
-
-    #[test]
-    fn test_part_two() {
-        // let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        let mut universe =
-            Universe::parse(&advent_of_code::template::read_file("examples", DAY), 9);
-        universe.expand();
-        let result = universe.count_shortest_paths();
-        assert_eq!(Some(result), Some(1030));
-    }
+    Ok(res as u64)
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let field: Field = Field::parse(input);
@@ -207,44 +207,6 @@ fn rhash(line: &[char], records: &[usize], cache: &mut HashMap<(usize, usize), u
 //     res
 // }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(525152));
-    }
-
-    #[test]
-    fn one_line() {
-        // An example line followed by it's permutations, 10
-        // ?###???????? 3,2,1
-        // .###.##.#...
-        // .###.##..#..
-        // .###.##...#.
-        // .###.##....#
-        // .###..##.#..
-        // .###..##..#.
-        // .###..##...#
-        // .###...##.#.
-        // .###...##..#
-        // .###....##.#
-
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(10));
-    }
-}
-
 // fn count_permutations(&mut self) -> usize {
 //     let mut count = 0;
 //     for row in &self.rows {
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(2023, 13);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut mountain = Mountain::parse(input);
@@ -121,14 +121,14 @@ fn flip(matrix: Vec<String>) -> Vec<String> {
     for i in 0..matrix[0].len() {
         let mut row = String::new();
         for j in (0..matrix.len()).rev() {
-            row.push_str(&matrix[j].chars().nth(i).unwrap().to_string());
+            row.push(matrix[j].chars().nth(i).unwrap());
         }
         flipped.push(row);
     }
     flipped
 }
 
-fn one_off(first: &String, second: &String) -> Option<usize> {
+fn one_off(first: &str, second: &str) -> Option<usize> {
     if first.len() != second.len() {
         return None; // Strings must be of equal length for comparison
     }
@@ -231,59 +231,3 @@ impl Mountain {
         count
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(405));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(400));
-    }
-
-    // #[test]
-    // fn test_unexpected_zeroes() {
-    //     let result = part_two(&advent_of_code::template::read_file_part("examples", DAY, 2));
-    //     assert_eq!(result, Some(400));
-    // }
-
-    #[test]
-    fn test_unexpected_zeroes_2() {
-        //original
-        // ##..#.##..###
-        // ....##...#.##
-        // ##.....##..##
-        // ...#..##..###
-        // ####..##..#..
-        // ..##..##.#.#.
-        // ...##.#...###
-
-        //flipped
-        // ..#.#.#
-        // ..#.#.#
-        // .##....
-        // ####...
-        // #....##
-        // .....#.
-        // ####..#
-        // .####.#
-        // ....#..
-        // .#...#.
-        // #.##..#
-        // #X.####
-        // #..####
-
-        // original
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
-        ));
-        assert_eq!(result, Some(12));
-    }
-}
@@ -3,7 +3,9 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-advent_of_code::solution!(14);
+use advent_of_code::cycles::find_cycle_by;
+
+advent_of_code::solution!(2023, 14);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut platform = Platform::parse(input);
@@ -85,7 +87,7 @@ struct Platform {
 impl Platform {
     fn parse(input: &str) -> Platform {
         let mut rocks: Vec<Vec<Object>> = Vec::new();
-        for (_y, row) in input.lines().enumerate() {
+        for row in input.lines() {
             let mut line = Vec::new();
             for (_x, char) in row.char_indices() {
                 // let coordinate = Coordinate { col: x, row: y };
@@ -186,31 +188,8 @@ impl Platform {
     }
 
     fn cycle_times(&mut self, amount: usize) -> usize {
-        let mut hash_states = vec![];
-        let mut weights = vec![];
-        hash_states.push(self.hash_state());
-        weights.push(self.count_weights());
-
-        for ticker in 1..=amount {
-            self.cycle();
-            let after = self.hash_state();
-            let weight = self.count_weights();
-            // println!("state {}, weight {}", ticker, weight);
-
-            if let Some(first_occurence) = hash_states.iter().position(|state| *state == after) {
-                let cycle_length = ticker - first_occurence;
-                // println!(
-                //     "occurence {}, cycle_length {}",
-                //     first_occurence, cycle_length
-                // );
-
-                return weights[((amount - first_occurence) % cycle_length) + first_occurence];
-            }
-
-            hash_states.push(after);
-            weights.push(weight);
-        }
-        *weights.last().unwrap()
+        let cycle = find_cycle_by(self, Self::cycle, Self::hash_state, Self::count_weights);
+        *cycle.at(amount)
     }
 
     fn cycle(&mut self) {
@@ -231,29 +210,3 @@ impl Platform {
         hasher.finish()
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(136));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(64));
-    }
-
-    // #[test]
-    // fn test_example_p2() {
-    //     let mut platform = Platform::parse(&advent_of_code::template::read_file("examples", DAY));
-    //     platform.cycle_times(3);
-    //     let result = Some(platform.count_weights());
-
-    //     assert_eq!(result, Some(3));
-    // }
-}
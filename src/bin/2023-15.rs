@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(2023, 15);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let sequence = Sequence::parse(input);
@@ -14,23 +14,6 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(result as u32)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1320));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(145));
-    }
-}
-
 /*
 BOXES
 There are 0..256 boxes
@@ -56,7 +39,7 @@ impl Sequence {
         let input = input.replace('\n', "");
         let strings: Vec<String> = input.split(',').map(|line|line.to_owned()).collect();
         let mut boxes: HashMap<u8, Vec<String>> = HashMap::new();
-        for i in 0..=255 as u8 {
+        for i in 0..=255_u8 {
             boxes.insert(i, vec![]);
         }
         Sequence { strings, boxes }
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 // code studied from
 //https://github.com/Zemogus/AOC-2023/blob/main/src/day16.rs
 
-advent_of_code::solution!(16);
+advent_of_code::solution!(2023, 16);
 
 pub fn part_one(_input: &str) -> Option<u32> {
     let tiles = parse(_input);
@@ -57,23 +57,6 @@ pub fn part_two(_input: &str) -> Option<u32> {
     Some(max_energised as u32)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(46));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(51));
-    }
-}
-
 enum Tile {
     Empty,
     FSlash,
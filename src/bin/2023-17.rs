@@ -1,15 +1,11 @@
 use std::collections::BinaryHeap;
 
-advent_of_code::solution!(17);
+use advent_of_code::search::dijkstra_buckets;
 
-pub fn part_one(_input: &str) -> Option<u16> {
-    let grid = parse(_input);
-    let grid = grid
-        .iter()
-        .map(|row| row.as_slice())
-        .collect::<Vec<_>>();
+advent_of_code::solution!(2023, 17, variants = [1: part_one_buckets, 2: part_two_buckets]);
 
-    lowest_loss(&grid, 1, 3)
+pub fn part_one(_input: &str) -> Option<u16> {
+    lowest_loss(_input, (1, 3), search_heap)
     // let path = astar(&grid, Node(0, 0), Node(grid[0].len() as i8, grid.len() as i8));
     // match path {
     //     Some(path) => Some(count_heat(path, &grid) as u32),
@@ -18,30 +14,28 @@ pub fn part_one(_input: &str) -> Option<u16> {
 }
 
 pub fn part_two(_input: &str) -> Option<u16> {
+    lowest_loss(_input, (4, 10), search_heap)
+}
+
+pub fn part_one_buckets(_input: &str) -> Option<u16> {
+    lowest_loss(_input, (1, 3), search_buckets)
+}
+
+pub fn part_two_buckets(_input: &str) -> Option<u16> {
+    lowest_loss(_input, (4, 10), search_buckets)
+}
+
+/// A search for the lowest heat loss, given the blocks and the minimum and maximum number of straight steps.
+type Search = fn(&[&[u8]], u8, u8) -> Option<u16>;
+
+fn lowest_loss(_input: &str, (min_straight, max_straight): (u8, u8), search: Search) -> Option<u16> {
     let grid = parse(_input);
     let grid = grid
         .iter()
         .map(|row| row.as_slice())
         .collect::<Vec<_>>();
 
-    lowest_loss(&grid, 4, 10)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(102));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(94));
-    }
+    search(&grid, min_straight, max_straight)
 }
 
 fn parse(_input: &str) -> Vec<Vec<u8>> {
@@ -56,7 +50,7 @@ fn parse(_input: &str) -> Vec<Vec<u8>> {
 }
 
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 enum Direction {
     Right = 0,
     Left,
@@ -123,7 +117,53 @@ impl <T>Array3D<T> where T: Copy{
         self.data[x + y * self.width + z * self.width * self.height] = value;
     }
 }
-fn lowest_loss(blocks: &[&[u8]], min_straight: u8, max_straight: u8) -> Option<u16> {
+
+/// Position of the crucible and the direction it last moved in.
+type State = (u8, u8, Direction);
+
+/// Calls `visit` with every state the crucible can turn and move to from `state`, and the heat loss on the way.
+fn successors(
+    blocks: &[&[u8]],
+    (x, y, d): State,
+    min_straight: u8,
+    max_straight: u8,
+    mut visit: impl FnMut(State, u16),
+) {
+    let directions = match d {
+        Direction::Right | Direction::Left => [Direction::Down, Direction::Up],
+        Direction::Down | Direction::Up => [Direction::Right, Direction::Left],
+        Direction::None => [Direction::Right, Direction::Down],
+    };
+
+    for next_d in directions {
+        let mut loss = 0;
+        for step in 1..=max_straight {
+            let (next_x, next_y) = match next_d {
+                Direction::Right => (x + step, y),
+                Direction::Left => (x.wrapping_sub(step), y),
+                Direction::Down => (x, y + step),
+                Direction::Up => (x, y.wrapping_sub(step)),
+                Direction::None => unreachable!(),
+            };
+
+            // Stay in bounds
+            if next_x as usize >= blocks[0].len() || next_y as usize >= blocks.len() {
+                break;
+            }
+
+            loss += blocks[next_y as usize][next_x as usize] as u16;
+
+            // Too early to turn
+            if step < min_straight {
+                continue;
+            }
+
+            visit((next_x, next_y, next_d), loss);
+        }
+    }
+}
+
+fn search_heap(blocks: &[&[u8]], min_straight: u8, max_straight: u8) -> Option<u16> {
     // Dijkstra's algorithm
     let start_x = 0;
     let start_y = 0;
@@ -146,53 +186,44 @@ fn lowest_loss(blocks: &[&[u8]], min_straight: u8, max_straight: u8) -> Option<u
             return Some(g_score);
         }
 
-        let directions = match d {
-            Direction::Right | Direction::Left => [Direction::Down, Direction::Up],
-            Direction::Down | Direction::Up => [Direction::Right, Direction::Left],
-            Direction::None => [Direction::Right, Direction::Down],
-        };
-        for next_d in directions {
-            let mut tentative_g_score = g_score;
-            for step in 1..=max_straight {
-                let (next_x, next_y) = match next_d {
-                    Direction::Right => (x + step, y),
-                    Direction::Left => (x.wrapping_sub(step), y),
-                    Direction::Down => (x, y + step),
-                    Direction::Up => (x, y.wrapping_sub(step)),
-                    Direction::None => unreachable!(),
-                };
-
-                // Stay in bounds
-                if next_x as usize >= blocks[0].len() || next_y as usize >= blocks.len() {
-                    break;
-                }
-
-                tentative_g_score = tentative_g_score
-                    .saturating_add(blocks[next_y as usize][next_x as usize] as u16);
-
-                // Too early to turn
-                if step < min_straight {
-                    continue;
-                }
-
-                if tentative_g_score < g_scores.get(next_x, next_y, next_d as u8) {
-                    // Found better path
-                    g_scores.set(next_x, next_y, next_d as u8, tentative_g_score);
-
-                    open.push(Node {
-                        x: next_x,
-                        y: next_y,
-                        d: next_d,
-                        g_score: tentative_g_score,
-                    });
-                }
+        successors(blocks, (x, y, d), min_straight, max_straight, |(next_x, next_y, next_d), loss| {
+            let tentative_g_score = g_score.saturating_add(loss);
+            if tentative_g_score < g_scores.get(next_x, next_y, next_d as u8) {
+                // Found better path
+                g_scores.set(next_x, next_y, next_d as u8, tentative_g_score);
+
+                open.push(Node {
+                    x: next_x,
+                    y: next_y,
+                    d: next_d,
+                    g_score: tentative_g_score,
+                });
             }
-        }
+        });
     }
 
     None
 }
 
+fn search_buckets(blocks: &[&[u8]], min_straight: u8, max_straight: u8) -> Option<u16> {
+    // same search as `search_heap`, but with the generic bucket queue of the library
+    let target_x = (blocks[0].len() - 1) as u8;
+    let target_y = (blocks.len() - 1) as u8;
+
+    let next_states = |&state: &State| {
+        let mut next = vec![];
+        successors(blocks, state, min_straight, max_straight, |state, loss| {
+            next.push((state, loss as usize));
+        });
+        next
+    };
+
+    let path = dijkstra_buckets((0, 0, Direction::None), next_states, |&(x, y, _)| {
+        (x, y) == (target_x, target_y)
+    })?;
+    Some(path.cost as u16)
+}
+
 // fn manhattan_distance(from: Node, to: Node) -> usize {
 //     (abs(from.0 - to.0) + abs(from.1 - to.1)).try_into().unwrap()
 // }
@@ -1,42 +1,32 @@
 use std::cmp::{ min, max };
 
-advent_of_code::solution!(18);
+use advent_of_code::{ geometry::{ lattice_points, walk }, grid::{ Direction, Point } };
 
-pub fn part_one(input: &str) -> Option<u32> {
+advent_of_code::solution!(2023, 18, variants = [1: part_one_grid, 1: part_one_shoelace]);
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let steps = parse_steps(input);
+    Some(lattice_points(&walk(Point::ORIGIN, steps)))
+}
+
+// digs the trench on a grid
+pub fn part_one_grid(input: &str) -> Option<u32> {
     let grid = Grid::parse(input);
     // grid.fill();
     // let result = grid.count_filled();
     let result = grid.lagoon_area();
-    println!("lagoon result: {}", result);
-    println!(" shoelace result: {}", grid.shoelace_formula());
     Some(result as u32)
 }
 
-pub fn part_two(input: &str) -> Option<i64> {
-    let vertices = parse2(input);
-    // for vert in &vertices {
-    //     println!("{:?}", vert);
-    // }
-    let result = lagoon_area(vertices);
-
-    Some(result as i64)
+// counts the trench on the grid, and the interior with the shoelace formula
+pub fn part_one_shoelace(input: &str) -> Option<u32> {
+    let grid = Grid::parse(input);
+    Some(grid.shoelace_formula() as u32)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(62));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(952408144115));
-    }
+pub fn part_two(input: &str) -> Option<u64> {
+    let steps = parse_hex_steps(input);
+    Some(lattice_points(&walk(Point::ORIGIN, steps)))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -94,7 +84,7 @@ impl Grid {
         let mut vertices = vec![];
 
         for line in lines {
-            let parts: Vec<&str> = line.trim().split_whitespace().collect();
+            let parts: Vec<&str> = line.split_whitespace().collect();
 
             let direction = parts[0].chars().next().unwrap();
 
@@ -103,12 +93,7 @@ impl Grid {
             let hex: String = parts[2]
                 .trim()
                 .chars()
-                .filter(|c| {
-                    match c {
-                        '(' | ')' | '#' => false,
-                        _ => true,
-                    }
-                })
+                .filter(|c| !matches!(c, '(' | ')' | '#'))
                 .collect();
 
             if hex.len() != 6 {
@@ -333,7 +318,7 @@ impl Grid {
 // }
 
 #[allow(dead_code)]
-fn print_grid(grid: &Vec<Vec<Hole>>, coord: Coordinate) {
+fn print_grid(grid: &[Vec<Hole>], coord: Coordinate) {
     println!();
     for (y, row) in grid.iter().enumerate() {
         println!();
@@ -354,74 +339,46 @@ fn print_grid(grid: &Vec<Vec<Hole>>, coord: Coordinate) {
     println!();
 }
 
-fn parse2(input: &str) -> Vec<(i64, i64)> {
-    let lines = input.lines().peekable();
-
-    let mut vertices = vec![];
-    let (mut x, mut y) = (0,0);
-
-    for line in lines {
-        let mut hex = line
-            .trim()
-            .split_ascii_whitespace()
-            .nth(2)
-            .map(|part| {
-                part.chars()
-                    .filter(|c| {
-                        match c {
-                            '(' | ')' | '#' => false,
-                            _ => true,
-                        }
-                    })
-                    .collect::<String>()
-            })
-            .unwrap();
-
-        let (distance,direction) ={ let (dist, dir) = hex.split_at_mut(5);
-            (i64::from_str_radix(&dist, 16).unwrap()  ,
-            dir.parse::<i8>().unwrap_or_default())};
-            // println!("{distance}");
-
-        vertices.push((x,y));
-        match direction {
-            0=> x += distance,
-            1=> y += distance,
-            2=> x -= distance,
-            3=> y -= distance,
-            _=> unreachable!(),
-        };
-    }
-    vertices.push((x, y));
-    vertices
+/// The direction and distance at the start of each line.
+fn parse_steps(input: &str) -> Vec<(Direction, i64)> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let direction = parts.next().and_then(|d| d.chars().next()).and_then(Direction::from_char).unwrap();
+            let distance = parts.next().unwrap().parse::<i64>().unwrap();
+            (direction, distance)
+        })
+        .collect()
 }
 
-fn lagoon_area(vertices: Vec<(i64, i64)>) -> u64 {
-    let inner_area = vertices
-        .windows(2)
-        .map(|w| {
-            // if y1 != y2, then x1==x2 and the area is 0
-            // otherwise y1==y2 => y1+y2 == 2(y1), so we can half it here
-            let (x1, y1) = (w[0].0 as i64, w[0].1 as i64);
-            let (x2, _) = (w[1].0 as i64, w[1].1 as i64);
-            (x2 - x1) * y1
-        })
-        .sum::<i64>()
-        .unsigned_abs();
-    // a border of width 0.5 because of integer coordinates
-    let border_area =
-        vertices
-            .windows(2)
-            .map(|w| {
-                let (x1, y1) = (w[0].0 as i64, w[0].1 as i64);
-                let (x2, y2) = (w[1].0 as i64, w[1].1 as i64);
-                x1.abs_diff(x2) + y1.abs_diff(y2)
-            })
-            .sum::<u64>() /
-            2 +
-        1;
-    //add +1 to accoutn for the 4 extra convex corners
+/// The direction and distance hidden in the colour, 5 hex digits of distance and one of direction.
+fn parse_hex_steps(input: &str) -> Vec<(Direction, i64)> {
+    input
+        .lines()
+        .map(|line| {
+            let hex = line
+                .trim()
+                .split_ascii_whitespace()
+                .nth(2)
+                .map(|part| {
+                    part.chars()
+                        .filter(|c| !matches!(c, '(' | ')' | '#'))
+                        .collect::<String>()
+                })
+                .unwrap();
 
-    inner_area + border_area
+            let (distance, direction) = hex.split_at(5);
+            let direction = match direction {
+                "0" => Direction::East,
+                "1" => Direction::South,
+                "2" => Direction::West,
+                "3" => Direction::North,
+                _ => unreachable!(),
+            };
+            (direction, i64::from_str_radix(distance, 16).unwrap())
+        })
+        .collect()
 }
 
 // fn shoelace_formula( vertices: Vec<(i64, i64)>) -> i64 {
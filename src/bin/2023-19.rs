@@ -1,12 +1,14 @@
 use std::collections::{HashMap, VecDeque};
 
+use advent_of_code::ranges::HyperRect;
+
 // #[allow(dead_code)]
 // const INPUT: &str = include_str!("../../data/inputs/19.txt");
 
-advent_of_code::solution!(19);
+advent_of_code::solution!(2023, 19, variants = [2: part_two_queue]);
 
 pub fn part_one(_input: &str) -> Option<u32> {
-    // let mut system = parse(&advent_of_code::template::read_file("examples", DAY));
+    // let mut system = parse(&advent_of_code::template::read_file("examples", PUZZLE));
     let mut system = parse(_input);
     let result = system.process();
     Some(result as u32)
@@ -14,10 +16,17 @@ pub fn part_one(_input: &str) -> Option<u32> {
 }
 
 pub fn part_two(_input: &str) -> Option<u64> {
+    let system = parse(_input);
+    // every rating is in 1..=4000
+    let all = HyperRect::new([1..4001, 1..4001, 1..4001, 1..4001]);
+    Some(system.count_accepted("in", all))
+}
+
+pub fn part_two_queue(_input: &str) -> Option<u64> {
     // let input = include_str!("../../data/inputs/19.txt");
 
     let system = parse(_input);
-    // let system = parse(&advent_of_code::template::read_file("examples", DAY));
+    // let system = parse(&advent_of_code::template::read_file("examples", PUZZLE));
 
     let result: u64 = system.non_recursive_precalculate();
 
@@ -28,23 +37,6 @@ pub fn part_two(_input: &str) -> Option<u64> {
     //125051049836302
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(19114));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(167409079868000));
-    }
-}
-
 struct System {
     workflows: HashMap<String, Vec<(String, Status)>>,
     parts: Vec<Part1>,
@@ -73,6 +65,9 @@ struct Part1 {
 
 type PartRange = [(u16, u16); 4];
 
+/// Ratings of x, m, a and s that a range of parts can have.
+type PartRegion = HyperRect<u16, 4>;
+
 struct Instruction {
     index: usize,
     num: u16,
@@ -82,17 +77,14 @@ struct Instruction {
 
 impl System {
     fn process(&mut self) -> usize {
-        let mut iter_parts = self.parts.iter_mut().enumerate();
-
-        'parts: while let Some((_id, part)) = iter_parts.next() {
+        'parts: for part in self.parts.iter_mut() {
             // println!("new part id {}", _id);
             let mut current_workflow: String = "in".to_string();
             'workflows: loop {
                 // println!("looking at workflow: {}", current_workflow);
                 if let Some(rules) = self.workflows.get(&current_workflow) {
-                    for i in 0..rules.len() {
-                        // println!("rule {}", i);
-                        let ruling = apply_rule(rules[i].0.clone(), part);
+                    for rule in rules {
+                        let ruling = apply_rule(rule.0.clone(), part);
                         match ruling {
                             Ruling::Accept => {
                                 // println!("accept");
@@ -127,11 +119,45 @@ impl System {
         accumulator
     }
 
+    fn count_accepted(&self, name: &str, mut region: PartRegion) -> u64 {
+        match name {
+            "A" => return region.volume() as u64,
+            "R" => return 0,
+            _ => (),
+        }
+
+        let mut count = 0;
+        for (rule, _) in &self.workflows[name] {
+            let Some((index, num, sign, _ruling, address)) = parse_rule(rule.clone()) else {
+                continue;
+            };
+
+            // split off the parts that match the rule, the rest moves on to the next rule
+            let (matched, rest) = match sign {
+                '<' => region.split(index, num),
+                '>' => {
+                    let (rest, matched) = region.split(index, num + 1);
+                    (matched, rest)
+                }
+                _ => (Some(region.clone()), None),
+            };
+
+            if let Some(matched) = matched {
+                count += self.count_accepted(&address, matched);
+            }
+            match rest {
+                Some(rest) => region = rest,
+                None => break,
+            }
+        }
+        count
+    }
+
     #[allow(dead_code)]
     fn non_recursive_precalculate(&self) -> u64 {
         let mut count = 0;
 
-        fn advance_current_rule(current_rule: &String) -> String {
+        fn advance_current_rule(current_rule: &str) -> String {
             let last_char = current_rule.chars().last();
             if let Some(ch) = last_char {
                 if let Some(digit) = ch.to_digit(10) {
@@ -139,16 +165,16 @@ impl System {
                     if new_digit > 9 {
                         panic!("Number exceeds 9, cannot advance rule.");
                     }
-                    let mut new_rule = current_rule.clone();
+                    let mut new_rule = current_rule.to_string();
                     new_rule.pop(); // Remove the last character
                     new_rule.push_str(&new_digit.to_string()); // Append the new digit
                     return new_rule;
                 } else {
-                    return current_rule.clone() + &0.to_string();
+                    return current_rule.to_string() + &0.to_string();
                 }
             }
             // If the last character is not a digit or something goes wrong, return the input unchanged
-            current_rule.clone()
+            current_rule.to_string()
         }
 
         let range: (u16, u16) = (1, 4001);
@@ -193,7 +219,7 @@ impl System {
                 },
                 _=> ()
             }
-            let mut counterpart = part.clone();
+            let mut counterpart = part;
 
             let Instruction { index, num, sign, address } = range_instructions
                 .get(&current_rule)
@@ -382,10 +408,10 @@ fn parse(input: &str) -> System {
 
     let mut parts_map: Vec<Part1> = vec![];
 
-    for (_id, line) in sections.next().unwrap().lines().enumerate() {
+    for line in sections.next().unwrap().lines() {
         let parts: Vec<&str> = line
             .trim()
-            .trim_matches(|c| (c == '{' || c == '}'))
+            .trim_matches(|c| c == '{' || c == '}')
             .split(',')
             .collect();
         let mut x = 0;
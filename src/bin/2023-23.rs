@@ -1,13 +1,13 @@
 use std::collections::{ HashMap, HashSet, VecDeque };
 
-advent_of_code::solution!(23);
+use advent_of_code::grid::{ Direction, Grid, Point };
 
-pub fn part_one(_input: &str) -> Option<u64> {
-    let grid = Grid::parse(_input);
-    let start = Coord { x: 1, y: 0 };
-    let end = Coord { x: grid.max_x - 2, y: grid.max_y - 1 };
+advent_of_code::solution!(2023, 23, parse = parse_map);
 
-    let mut q: VecDeque<(Coord, usize, HashSet<Coord>)> = VecDeque::new();
+pub fn part_one(grid: &Grid<Tile>) -> Option<u64> {
+    let (start, end) = start_and_end(grid);
+
+    let mut q: VecDeque<(Point, usize, HashSet<Point>)> = VecDeque::new();
     let mut max = 0;
 
     q.push_back((start, 0, HashSet::from([start])));
@@ -18,10 +18,8 @@ pub fn part_one(_input: &str) -> Option<u64> {
             continue;
         }
 
-        let neighbours1 = pos.get_neighbors(&grid);
-        for (dir, coord) in neighbours1 {
-            let tile = grid.get(&coord).unwrap();
-            match tile {
+        for (dir, coord) in paths(grid, pos) {
+            match grid[coord] {
                 Tile::Path => {
                     if seen.insert(coord) {
                         q.push_back((coord, cost + 1, seen.clone()))
@@ -42,7 +40,7 @@ pub fn part_one(_input: &str) -> Option<u64> {
 
 
 
-fn longest(from: Coord, to: Coord, map: &HashMap<Coord, HashMap<Coord, usize>>) -> usize {
+fn longest(from: Point, to: Point, map: &HashMap<Point, HashMap<Point, usize>>) -> usize {
     let mut q = VecDeque::new();
     let mut max = 0;
 
@@ -69,14 +67,14 @@ fn longest(from: Coord, to: Coord, map: &HashMap<Coord, HashMap<Coord, usize>>)
     max
 }
 
-fn costmap(points: &HashSet<Coord>, map: &Grid) -> HashMap<Coord, HashMap<Coord, usize>> {
+fn costmap(points: &HashSet<Point>, map: &Grid<Tile>) -> HashMap<Point, HashMap<Point, usize>> {
     let initial = HashMap::from_iter(points.iter().map(|node| (*node, HashMap::new())));
 
     points.iter().fold(initial, |mut acc, point| {
         // add the cost of every reachable point.
         // when you reach a point, keep going and remember where you've been so you don't try to visit impossible points
-        let mut q: VecDeque<(Coord, usize)> = VecDeque::new();
-        let mut seen: HashSet<Coord> = HashSet::new();
+        let mut q: VecDeque<(Point, usize)> = VecDeque::new();
+        let mut seen: HashSet<Point> = HashSet::new();
         q.push_back((*point, 0));
 
         while let Some((pos, cost)) = q.pop_front() {
@@ -87,7 +85,7 @@ fn costmap(points: &HashSet<Coord>, map: &Grid) -> HashMap<Coord, HashMap<Coord,
             }
 
             // go to an adjacent tile if it's not already seen during this path
-            for (_, &n) in pos.get_neighbors(map).iter() {
+            for (_, n) in paths(map, pos) {
                 if seen.insert(n) {
                     q.push_back((n, cost + 1));
                 }
@@ -102,159 +100,55 @@ fn costmap(points: &HashSet<Coord>, map: &Grid) -> HashMap<Coord, HashMap<Coord,
 
 
 
-pub fn part_two(_input: &str) -> Option<u64> {
-    let grid = Grid::parse(_input);
-    let start = Coord { x: 1, y: 0 };
-    let end = Coord { x: grid.max_x - 2, y: grid.max_y - 1 };
+pub fn part_two(grid: &Grid<Tile>) -> Option<u64> {
+    let (start, end) = start_and_end(grid);
 
-    let mut points: HashSet<Coord> = grid.all_points();
+    let mut points: HashSet<Point> = crossroads(grid);
     points.insert(start);
     points.insert(end);
 
-    let graph: HashMap<Coord, HashMap<Coord, usize>> = costmap(&points, &grid);
+    let graph: HashMap<Point, HashMap<Point, usize>> = costmap(&points, grid);
     Some(longest(start,end, &graph) as u64)
     // None
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
-struct Coord {
-    x: usize,
-    y: usize,
+fn start_and_end(grid: &Grid<Tile>) -> (Point, Point) {
+    let start = Point::new(1, 0);
+    let end = Point::new(grid.width() as i64 - 2, grid.height() as i64 - 1);
+    (start, end)
 }
 
-impl std::fmt::Display for Coord {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
+/// Neighbours of a point that are not forest, with the direction to reach them.
+fn paths(grid: &Grid<Tile>, pos: Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+    Direction::ALL
+        .into_iter()
+        .map(move |dir| (dir, pos + dir))
+        .filter(|(_, coord)| grid.get(*coord).is_some_and(|tile| *tile != Tile::Forest))
 }
 
-impl Coord {
-    fn get_neighbors(&self, grid: &Grid) -> HashMap<Direction, Coord> {
-        let mut result = HashMap::new();
-        if self.x > 0 {
-            result.insert(Direction::West, Coord { x: self.x - 1, y: self.y });
-        }
-        if self.y > 0 {
-            result.insert(Direction::North, Coord { x: self.x, y: self.y - 1 });
-        }
-        if self.x < grid.max_x - 1 {
-            result.insert(Direction::East, Coord { x: self.x + 1, y: self.y });
-        }
-        if self.y < grid.max_y - 1 {
-            result.insert(Direction::South, Coord { x: self.x, y: self.y + 1 });
-        }
-        result
-            .iter()
-            .filter(|(_, coord)| grid.get(coord).unwrap() != Tile::Forest)
-            .map(|entry| (*entry.0, *entry.1))
-            .collect()
-    }
-
-    // fn manhattan_distance(&self, other: &Coord) -> usize {
-    //     (((self.x as isize) - (other.x as isize)).abs() +
-    //         ((self.y as isize) - (other.y as isize)).abs()) as usize
-    // }
+/// Points where the path splits up.
+fn crossroads(grid: &Grid<Tile>) -> HashSet<Point> {
+    grid.iter()
+        .filter(|(coord, tile)| **tile != Tile::Forest && paths(grid, *coord).count() > 2)
+        .map(|(coord, _)| coord)
+        .collect()
 }
 
 #[derive(PartialEq, Clone, Copy)]
-enum Tile {
+pub enum Tile {
     Path,
     Forest,
     Slope(Direction),
 }
 
-#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-// impl Direction {
-//     fn reverse(&self) -> Direction {
-//         match self {
-//             Direction::North => Direction::South,
-//             Direction::East => Direction::West,
-//             Direction::South => Direction::North,
-//             Direction::West => Direction::East,
-//         }
-//     }
-// }
-
-struct Grid {
-    grid: Vec<Vec<Tile>>,
-    max_x: usize,
-    max_y: usize,
-}
-
-impl Grid {
-    fn parse(_input: &str) -> Self {
-        let grid: Vec<Vec<Tile>> = _input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|char| {
-                        match char {
-                            '.' => Tile::Path,
-                            '#' => Tile::Forest,
-                            '^' => Tile::Slope(Direction::North),
-                            '>' => Tile::Slope(Direction::East),
-                            'v' => Tile::Slope(Direction::South),
-                            '<' => Tile::Slope(Direction::West),
-                            _ => unreachable!(),
-                        }
-                    })
-                    .collect()
-            })
-            .collect();
-        let max_x = grid[0].len();
-        let max_y = grid.len();
-        Self { grid, max_x, max_y }
-    }
-
-    fn get(&self, coord: &Coord) -> Option<Tile> {
-        // if coord is within bounds
-        Some(self.grid[coord.y][coord.x])
-        // else none
-    }
-
-    fn all_points(&self) -> HashSet<Coord> {
-        let mut res = HashSet::new();
-        for y in 0..self.max_y {
-            for x in 0..self.max_x {
-                let coord = Coord { x, y };
-                let tile = self.get(&coord).unwrap();
-                if tile != Tile::Forest && coord.get_neighbors(self).iter().count() > 2 {
-                    res.insert(coord);
-                }
-            }
+fn parse_map(_input: &str) -> Grid<Tile> {
+    Grid::parse_with(_input, |char| {
+        match char {
+            '.' => Tile::Path,
+            '#' => Tile::Forest,
+            _ => Tile::Slope(Direction::from_char(char).unwrap()),
         }
-        res
-    }
-
-    // fn directional_points(&self) -> HashSet<(Option<Direction>, Coord)> {
-    //     let mut res = HashSet::new();
-    //     for y in 0..self.max_y {
-    //         for x in 0..self.max_x {
-    //             let coord = Coord { x, y };
-    //             let tile = self.get(&coord).unwrap();
-    //             match tile {
-    //                 Tile::Slope(direction) => {
-    //                     res.insert((Some(direction), coord));
-    //                     continue;
-    //                 },
-    //                 Tile::Forest => (),
-    //                 Tile::Path => (),
-    //             }
-    //             if tile != Tile::Forest && coord.get_neighbors(self).iter().count() > 2 {
-    //                 res.insert((None,coord));
-    //             }
-
-    //         }
-    //     }
-    //     res
-    // }
+    })
 }
 
 // struct Node {
@@ -417,20 +311,3 @@ impl Grid {
 //         current = *neighbors.get(&current_direction).unwrap();
 //     }
 // }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(94));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(154));
-    }
-}
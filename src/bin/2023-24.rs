@@ -0,0 +1,215 @@
+use std::{ fmt::Error, str::FromStr };
+
+use advent_of_code::{ linear::{ rank, solve }, rational::Rational, template::params::param };
+
+advent_of_code::solution!(2023, 24);
+
+pub fn part_one(_input: &str) -> Result<u64, String> {
+    let min: i64 = param("min", 200_000_000_000_000)?;
+    let max: i64 = param("max", 400_000_000_000_000)?;
+    let hail = _input
+        .lines()
+        .map(|line| Hail::from_str(line).unwrap())
+        .collect::<Vec<_>>();
+    let (min, max) = (Rational::from(min), Rational::from(max));
+    let intersections = intersections_xy(&hail);
+    let result = intersections
+        .iter()
+        .flatten()
+        .filter(|(x, y)| *x >= min && *x <= max && *y >= min && *y <= max)
+        .count();
+    Ok(result as u64)
+}
+
+pub fn part_two(_input: &str) -> Option<u64> {
+    // https://github.com/sebastianotronto/aoc/blob/master/2023/24/24b.c
+
+    let hailstones = _input
+        .lines()
+        .map(|line| Hail::from_str(line).unwrap())
+        .collect::<Vec<_>>();
+    if hailstones.len() < 3 {
+        return None; // Not enough hail
+    }
+
+    /* To figure the correct starting point + velocity, we solve some
+	 * systems of linear equations. Write your starting point and velocity
+	 * as unknowns x, y, z, Vx, Vy, Vz. Equating the position of the
+	 * rock at time t1 (another unknown parameter) with the position
+	 * of one of the hailstones at the same time t1, we get a system
+	 * of 3 equations and 7 unknowns. Unfortunately, these equations
+	 * have degree 2 - this is not a linear system!
+	 * However, manipulating these equations a bit we can get a linear
+	 * equation of the type:
+	 *     (Vy1-Vy2)x - (Vx1-Vx2)y + - (y1-y2)Vx + (x1-x2)Vy =
+	 *         y2Vx2 + x2Vy2 - y1Vx1 - x1Vy1
+	 * Where x1, y1, z2, Vx1, Vy1, Vz1 and x2, y2, z2, Vx2, Vy2, Vz2
+	 * are the starting points and velocities of two of the hailstones.
+	 * So with 2 lines we can get a linear equation. Similarly, we can
+	 * get equations involving the unknowns z and Vz.
+	 * We can use the myriad of hailstones we have to generate as many
+	 * equations as we like. The system is going to be overdetermined,
+	 * but the problem statement seems to ensure that there is going to
+	 * be a solution. On the other hand it can happen that we make a
+	 * bad choice of lines and the equation we use are underdetermined.
+	 * So we go through all pairs of hailstones and keep every equation
+	 * that is independent of the ones before it, until six of them pin
+	 * down the rock.
+	 */
+
+    let mut matrix = vec![];
+    let mut rhs = vec![];
+    let pairs = (0..hailstones.len())
+        .flat_map(|i| (i + 1..hailstones.len()).map(move |j| (i, j)));
+    'pairs: for (i, j) in pairs {
+        for (a, b) in [(0, 1), (0, 2), (1, 2)] {
+            let (row, value) = rock_equation(&hailstones[i], &hailstones[j], a, b);
+            matrix.push(row.map(Rational::from).to_vec());
+            if rank(&matrix) < matrix.len() {
+                matrix.pop();
+                continue;
+            }
+            rhs.push(Rational::from(value));
+            if matrix.len() == 6 {
+                break 'pairs;
+            }
+        }
+    }
+
+    // Solve the system, x, y, z, Vx, Vy, Vz of the rock
+    let rock = solve(&matrix, &rhs).unique()?;
+    let sum = &(&rock[0] + &rock[1]) + &rock[2];
+    sum.to_integer()?.to_i128()?.try_into().ok()
+}
+
+/// One linear equation in the rock's x, y, z, Vx, Vy, Vz, from two hailstones and the axes `a` and `b`.
+fn rock_equation(first: &Hail, other: &Hail, a: usize, b: usize) -> ([i128; 6], i128) {
+    let (p1, v1) = (first.p.axes(), first.v.axes());
+    let (p2, v2) = (other.p.axes(), other.v.axes());
+
+    let mut row = [0; 6];
+    row[a] = v1[b] - v2[b];
+    row[b] = v2[a] - v1[a];
+    row[3 + a] = p2[b] - p1[b];
+    row[3 + b] = p1[a] - p2[a];
+    let value = p2[b] * v2[a] - p2[a] * v2[b] - p1[b] * v1[a] + p1[a] * v1[b];
+    (row, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let s = "19, 13, 30 @ -2,  1, -2";
+        let hail = Hail {
+            p: Vector { x: 19, y: 13, z: 30 },
+            v: Vector { x: -2, y: 1, z: -2 },
+        };
+        let result = Hail::from_str(s).unwrap();
+        assert_eq!(result, hail);
+    }
+
+    #[test]
+    fn advance_time() {
+        let hail = Hail {
+            p: Vector { x: 20, y: 19, z: 15 },
+            v: Vector { x: 1, y: -5, z: -3 },
+        };
+        let result = hail.position_at(1);
+        assert_eq!(result, Vector { x: 21, y: 14, z: 12 })
+    }
+
+    #[test]
+    fn intersect_paths() {
+        let a = Hail::from_str("19, 13, 30 @ -2, 1, -2").unwrap();
+        let b = Hail::from_str("18, 19, 22 @ -1, -1, -2").unwrap();
+        let result = a.intersect_xy(&b);
+        assert_eq!(result, Some((Rational::new(43, 3), Rational::new(46, 3))));
+
+        let a = Hail::from_str("19, 13, 30 @ -2, 1, -2").unwrap();
+        let b = Hail::from_str("20, 25, 34 @ -2, -2, -4").unwrap();
+        let result = a.intersect_xy(&b);
+        assert_eq!(result, Some((Rational::new(35, 3), Rational::new(50, 3))));
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Vector {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Vector {
+    fn axes(&self) -> [i128; 3] {
+        [self.x as i128, self.y as i128, self.z as i128]
+    }
+}
+
+
+#[derive(Debug, PartialEq)]
+struct Hail {
+    p: Vector,
+    v: Vector,
+}
+impl Hail {
+    #[allow(dead_code)]
+    fn position_at(&self, nanoseconds: i64) -> Vector {
+        Vector {
+            x: self.p.x + self.v.x * nanoseconds,
+            y: self.p.y + self.v.y * nanoseconds,
+            z: self.p.z + self.v.z * nanoseconds,
+        }
+    }
+
+    fn intersect_xy(&self, other: &Hail) -> Option<(Rational, Rational)> {
+        // self.p + t * self.v == other.p + s * other.v, ignoring the z axis
+        let matrix = vec![
+            vec![Rational::from(self.v.x), Rational::from(-other.v.x)],
+            vec![Rational::from(self.v.y), Rational::from(-other.v.y)]
+        ];
+        let rhs = vec![Rational::from(other.p.x - self.p.x), Rational::from(other.p.y - self.p.y)];
+
+        // parallel paths never cross, or lie on top of each other which the puzzle does not have
+        let [t, s]: [Rational; 2] = solve(&matrix, &rhs).unique()?.try_into().ok()?;
+
+        // a negative time means the paths crossed in the past
+        if t.is_negative() || s.is_negative() {
+            return None;
+        }
+
+        let x = Rational::from(self.p.x) + &t * &Rational::from(self.v.x);
+        let y = Rational::from(self.p.y) + &t * &Rational::from(self.v.y);
+        Some((x, y))
+    }
+}
+
+impl FromStr for Hail {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // px, py, pz, @ vx, vy, vz
+        let p: Vec<i64> = s
+            .replace("@", "")
+            .replace(",", "")
+            .split_whitespace()
+            .map(|e| e.trim().parse::<i64>().unwrap())
+            .collect();
+        Ok(Self {
+            p: Vector { x: p[0], y: p[1], z: p[2] },
+            v: Vector { x: p[3], y: p[4], z: p[5] },
+        })
+    }
+}
+
+fn intersections_xy(hail: &[Hail]) -> Vec<Option<(Rational, Rational)>> {
+    let mut res = Vec::new();
+    for (i, h1) in hail.iter().enumerate() {
+        for h2 in hail.iter().skip(i + 1) {
+            res.push(h1.intersect_xy(h2));
+        }
+    }
+    res
+}
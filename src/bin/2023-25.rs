@@ -1,11 +1,11 @@
-use std::collections::{ HashMap, HashSet, VecDeque };
+use std::collections::{ HashMap, HashSet };
 
+use advent_of_code::search::bfs;
 use itertools::Itertools;
 
-advent_of_code::solution!(25);
+advent_of_code::solution!(2023, 25, parse = parse);
 
-pub fn part_one(_input: &str) -> Option<u64> {
-    let graph = parse(_input);
+pub fn part_one(graph: &HashMap<&str, HashSet<&str>>) -> Option<u64> {
     let result = graph
     .keys()
     .tuple_combinations()
@@ -28,27 +28,10 @@ pub fn part_one(_input: &str) -> Option<u64> {
     Some(result as u64)
 }
 
-pub fn part_two(_input: &str) -> Option<u64> {
+pub fn part_two(_graph: &HashMap<&str, HashSet<&str>>) -> Option<u64> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(54));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
-
 fn parse(input: &str) -> HashMap<&str, HashSet<&str>> {
     input
         .lines()
@@ -63,48 +46,37 @@ fn parse(input: &str) -> HashMap<&str, HashSet<&str>> {
 }
 
 fn delete_route<'a>(graph: &mut HashMap<&'a str, HashSet<&'a str>>, from: &'a str, to: &'a str) {
-    let mut q = VecDeque::from([from]);
-    let mut seen = HashSet::from([from]);
-    let mut prev_map = HashMap::new();
-
-    'outer: while let Some(node) = q.pop_front() {
-        for &neighbour in graph.get(node).unwrap() {
-            if seen.insert(neighbour) {
-                q.push_back(neighbour);
-                prev_map.insert(neighbour, node);
-                if neighbour == to {
-                    break 'outer;
-                }
-            }
-        }
-    }
+    let Some(path) = bfs(from, |node| graph[node].iter().copied(), |node| *node == to) else {
+        return;
+    };
 
     // delete every edge on the path "from"-"to"
     // if "from" and "to" were in the 2 halves, one of the connecting edges is guaranteed to be deleted this way
-    let mut curr = to;
-    while curr != from {
-        let prev = prev_map.get(curr).unwrap();
-        graph.entry(curr).or_default().remove(prev);
-        graph.entry(prev).or_default().remove(curr);
-        curr = prev;
+    for edge in path.states.windows(2) {
+        graph.entry(edge[0]).or_default().remove(edge[1]);
+        graph.entry(edge[1]).or_default().remove(edge[0]);
     }
 }
 
 fn reachable_nodes(graph: &HashMap<&str, HashSet<&str>>, from: &str, to: &str) -> Option<usize> {
-    let mut q = VecDeque::from([from]);
-    let mut seen = HashSet::from([from]);
+    // every node is checked once, so without reaching "to" the count is the size of the half
+    let mut seen = 0;
+    let path = bfs(from, |node| graph[node].iter().copied(), |node| {
+        seen += 1;
+        *node == to
+    });
+
+    // if "to" was reached, the graph was not cut in 2
+    path.is_none().then_some(seen)
+}
 
-    while let Some(node) = q.pop_front() {
-        for neighbour in graph.get(node).unwrap() {
-            if *neighbour == to {
-                // the graph was not cut in 2
-                return None;
-            }
-            if seen.insert(neighbour) {
-                q.push_back(neighbour);
-            }
-        }
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Some(seen.len())
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)));
+        assert_eq!(result, None);
+    }
 }
@@ -0,0 +1,194 @@
+/// Cycle detection for puzzles that ask for the state of a machine after an absurd number of steps.
+///
+/// Such machines are deterministic and have finitely many states, so they end up repeating themselves. Once the start
+/// and the length of the cycle are known, the state after any number of steps can be looked up instead of simulated.
+use std::{collections::HashMap, hash::Hash};
+
+/// A series of values that repeats itself from step `start` on, every `length` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T> {
+    /// Number of steps before the cycle is entered.
+    pub start: usize,
+    /// Number of steps in one repetition of the cycle.
+    pub length: usize,
+    /// The value at every step up to the end of the first repetition, the value of the initial state first.
+    pub series: Vec<T>,
+}
+
+impl<T> Cycle<T> {
+    /// Index into `series` of the value at step `n`.
+    #[must_use]
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// The value at step `n`.
+    #[must_use]
+    pub fn at(&self, n: usize) -> &T {
+        &self.series[self.index(n)]
+    }
+
+    /// Sum of the values of the first `n` steps, i.e. of steps `0..n`.
+    #[must_use]
+    pub fn sum_until(&self, n: usize) -> i128
+    where
+        T: Copy + Into<i128>,
+    {
+        let sum = |values: &[T]| values.iter().map(|value| (*value).into()).sum::<i128>();
+
+        if n <= self.series.len() {
+            return sum(&self.series[..n]);
+        }
+
+        let cycle = &self.series[self.start..];
+        let repetitions = (n - self.start) / self.length;
+        let rest = (n - self.start) % self.length;
+
+        sum(&self.series[..self.start]) + sum(cycle) * repetitions as i128 + sum(&cycle[..rest])
+    }
+}
+
+/// Step an owned state until it repeats itself, recording every state on the way.
+///
+/// Does not return if the states never repeat.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+    let mut state = initial;
+    find_cycle_by(
+        &mut state,
+        |state| *state = step(state),
+        Clone::clone,
+        Clone::clone,
+    )
+}
+
+/// Step a state in place until its fingerprint repeats itself, recording the value of every state on the way.
+///
+/// The fingerprint identifies a state, e.g. a hash of a large grid. The value is whatever the puzzle asks for, e.g.
+/// the load on a platform. The state is left at the start of the second repetition of the cycle.
+/// Does not return if the fingerprints never repeat.
+pub fn find_cycle_by<S, K, T>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut fingerprint: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> T,
+) -> Cycle<T>
+where
+    K: Eq + Hash,
+{
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut series = vec![];
+
+    loop {
+        let n = series.len();
+        let key = fingerprint(state);
+        if let Some(&start) = seen.get(&key) {
+            return Cycle {
+                start,
+                length: n - start,
+                series,
+            };
+        }
+
+        seen.insert(key, n);
+        series.push(value(state));
+        step(state);
+    }
+}
+
+/// Brent's algorithm, which finds the `(start, length)` of a cycle without remembering the states it has seen.
+///
+/// This trades memory for steps, it is useful when states are large and cheap to compute.
+/// Does not return if the states never repeat.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (usize, usize) {
+    // find the length by letting the hare run ahead in powers of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // find the start by moving two states that are `length` apart until they meet.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    (start, length)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{brent, find_cycle, find_cycle_by};
+
+    /// 0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, 3, ...
+    fn step(x: &u32) -> u32 {
+        if *x < 6 {
+            x + 1
+        } else {
+            3
+        }
+    }
+
+    #[test]
+    fn finds_cycles() {
+        let cycle = find_cycle(0, step);
+        assert_eq!(cycle.start, 3);
+        assert_eq!(cycle.length, 4);
+        assert_eq!(cycle.series, vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(*cycle.at(2), 2);
+        assert_eq!(*cycle.at(7), 3);
+        assert_eq!(*cycle.at(1_000_000_000), 4);
+        assert_eq!(brent(0, step), (3, 4));
+    }
+
+    #[test]
+    fn finds_cycles_of_fingerprints() {
+        // the value keeps growing, only the fingerprint repeats.
+        let mut state = (0, 0u64);
+        let cycle = find_cycle_by(
+            &mut state,
+            |(x, total)| {
+                *x = step(x);
+                *total += 1;
+            },
+            |(x, _)| *x,
+            |(_, total)| *total,
+        );
+        assert_eq!((cycle.start, cycle.length), (3, 4));
+        assert_eq!(cycle.series, vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(state, (3, 7));
+    }
+
+    #[test]
+    fn sums_series() {
+        let cycle = find_cycle(0, step);
+        assert_eq!(cycle.sum_until(0), 0);
+        assert_eq!(cycle.sum_until(7), 21);
+        // 0 + 1 + 2 + (3 + 4 + 5 + 6) * 2 + 3 + 4
+        assert_eq!(cycle.sum_until(13), 46);
+        assert_eq!(cycle.sum_until(1_000_003), 3 + 18 * 250_000);
+    }
+}
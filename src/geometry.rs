@@ -0,0 +1,219 @@
+/// Polygons on the integer lattice, e.g. a loop of pipes or a trench dug by following instructions.
+///
+/// A polygon is a slice of its corners in order, the edge from the last corner back to the first one is implied.
+/// Edges may be of any slope, but the polygon must not cross itself. Points use the coordinates of
+/// [`crate::grid`], with `y` growing downwards.
+use crate::{
+    grid::{Direction, Point},
+    math::gcd,
+};
+
+/// Twice the area of the polygon by the shoelace formula. It is positive if the corners go clockwise on screen and
+/// negative if they go counter-clockwise. Twice the area is always an integer on the lattice.
+#[must_use]
+pub fn signed_double_area(vertices: &[Point]) -> i64 {
+    edges(vertices).map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+}
+
+/// Twice the area of the polygon, regardless of the direction of its corners.
+#[must_use]
+pub fn double_area(vertices: &[Point]) -> u64 {
+    signed_double_area(vertices).unsigned_abs()
+}
+
+/// Number of lattice points on the edges of the polygon, including the corners.
+#[must_use]
+pub fn boundary_points(vertices: &[Point]) -> u64 {
+    edges(vertices)
+        .map(|(a, b)| gcd((b.x - a.x).unsigned_abs(), (b.y - a.y).unsigned_abs()))
+        .sum()
+}
+
+/// Number of lattice points strictly inside the polygon, by Pick's theorem `A = I + B / 2 - 1`.
+///
+/// A polygon without area, e.g. fewer than three corners or a walk that retraces its steps, has no interior points.
+///
+/// # Panics
+/// If the polygon has more boundary points than its area allows, which only happens if it crosses itself.
+#[must_use]
+pub fn interior_points(vertices: &[Point]) -> u64 {
+    let double_area = double_area(vertices);
+    if double_area == 0 {
+        return 0;
+    }
+
+    (double_area + 2)
+        .checked_sub(boundary_points(vertices))
+        .expect("polygon must not cross itself")
+        / 2
+}
+
+/// Number of lattice points inside the polygon or on its edges, e.g. the cells dug out by a trench and everything
+/// it encloses.
+#[must_use]
+pub fn lattice_points(vertices: &[Point]) -> u64 {
+    interior_points(vertices) + boundary_points(vertices)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// How often the polygon winds around `point`, zero if the point is outside. The sign depends on the direction of
+/// the corners. Points on an edge have no well-defined winding number, see [`on_boundary`].
+#[must_use]
+pub fn winding_number(vertices: &[Point], point: Point) -> i64 {
+    let mut winding = 0;
+    for (a, b) in edges(vertices) {
+        // which side of the edge the point lies on, zero if it lies on the line through it.
+        let side = (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y);
+        if a.y <= point.y && b.y > point.y && side > 0 {
+            winding += 1;
+        } else if a.y > point.y && b.y <= point.y && side < 0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// `true` if `point` lies on one of the edges of the polygon.
+#[must_use]
+pub fn on_boundary(vertices: &[Point], point: Point) -> bool {
+    edges(vertices).any(|(a, b)| {
+        let side = (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y);
+        side == 0
+            && (a.x.min(b.x)..=a.x.max(b.x)).contains(&point.x)
+            && (a.y.min(b.y)..=a.y.max(b.y)).contains(&point.y)
+    })
+}
+
+/// `true` if `point` lies inside the polygon or on one of its edges.
+#[must_use]
+pub fn contains(vertices: &[Point], point: Point) -> bool {
+    on_boundary(vertices, point) || winding_number(vertices, point) != 0
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The corners visited by walking from `start` the given distance in each direction in turn.
+///
+/// `start` is the first corner. If the walk ends where it started, that point is not repeated at the end.
+#[must_use]
+pub fn walk(start: Point, steps: impl IntoIterator<Item = (Direction, i64)>) -> Vec<Point> {
+    let mut vertices = vec![start];
+    let mut position = start;
+    for (direction, distance) in steps {
+        position += direction.offset() * distance;
+        vertices.push(position);
+    }
+
+    if vertices.len() > 1 && vertices.last() == Some(&start) {
+        vertices.pop();
+    }
+    vertices
+}
+
+/// Every edge of the polygon, including the one that closes it.
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        boundary_points, contains, double_area, interior_points, lattice_points, on_boundary,
+        signed_double_area, walk, winding_number,
+    };
+    use crate::grid::{Direction, Point};
+
+    fn points(coordinates: &[(i64, i64)]) -> Vec<Point> {
+        coordinates.iter().map(|&c| Point::from(c)).collect()
+    }
+
+    #[test]
+    fn measures_polygons() {
+        // a 4 by 3 rectangle, clockwise on screen.
+        let rectangle = points(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+        assert_eq!(signed_double_area(&rectangle), 24);
+        assert_eq!(boundary_points(&rectangle), 14);
+        assert_eq!(interior_points(&rectangle), 3 * 2);
+        assert_eq!(lattice_points(&rectangle), 5 * 4);
+
+        let reversed = rectangle.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(signed_double_area(&reversed), -24);
+        assert_eq!(double_area(&reversed), 24);
+
+        // slanted edges only have lattice points where both steps divide evenly.
+        let triangle = points(&[(0, 0), (4, 2), (0, 4)]);
+        assert_eq!(double_area(&triangle), 16);
+        assert_eq!(boundary_points(&triangle), 2 + 2 + 4);
+        assert_eq!(interior_points(&triangle), 5);
+    }
+
+    #[test]
+    fn measures_degenerate_polygons() {
+        assert_eq!(interior_points(&[]), 0);
+        assert_eq!(interior_points(&points(&[(2, 3)])), 0);
+        assert_eq!(interior_points(&points(&[(0, 0), (4, 0)])), 0);
+
+        // there and back again encloses nothing.
+        let line = walk(Point::ORIGIN, [(Direction::East, 3), (Direction::West, 3)]);
+        assert_eq!(double_area(&line), 0);
+        assert_eq!(boundary_points(&line), 6);
+        assert_eq!(interior_points(&line), 0);
+    }
+
+    #[test]
+    fn locates_points() {
+        // a U shape, the notch at the top is outside.
+        let shape = points(&[
+            (0, 0),
+            (2, 0),
+            (2, 2),
+            (4, 2),
+            (4, 0),
+            (6, 0),
+            (6, 4),
+            (0, 4),
+        ]);
+        assert_eq!(winding_number(&shape, Point::new(1, 1)).abs(), 1);
+        assert_eq!(winding_number(&shape, Point::new(3, 1)), 0);
+        assert_eq!(winding_number(&shape, Point::new(7, 1)), 0);
+
+        assert_eq!(on_boundary(&shape, Point::new(3, 2)), true);
+        assert_eq!(on_boundary(&shape, Point::new(3, 3)), false);
+        assert_eq!(contains(&shape, Point::new(3, 2)), true);
+        assert_eq!(contains(&shape, Point::new(3, 3)), true);
+        assert_eq!(contains(&shape, Point::new(3, 1)), false);
+
+        // the count from Pick's theorem agrees with testing every point.
+        let inside = (1..6)
+            .flat_map(|x| (1..4).map(move |y| Point::new(x, y)))
+            .filter(|p| !on_boundary(&shape, *p) && winding_number(&shape, *p) != 0)
+            .count();
+        assert_eq!(inside as u64, interior_points(&shape));
+    }
+
+    #[test]
+    fn walks_polygons() {
+        let steps = [
+            (Direction::East, 6),
+            (Direction::South, 5),
+            (Direction::West, 2),
+            (Direction::South, 2),
+            (Direction::West, 4),
+            (Direction::North, 7),
+        ];
+        let vertices = walk(Point::ORIGIN, steps);
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(vertices[1], Point::new(6, 0));
+        assert_eq!(vertices[5], Point::new(0, 7));
+        assert_eq!(lattice_points(&vertices), 7 * 6 + 5 * 2);
+
+        // an open walk keeps its end.
+        assert_eq!(walk(Point::ORIGIN, [(Direction::South, 2)]).len(), 2);
+    }
+}
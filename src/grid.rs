@@ -0,0 +1,540 @@
+/// A dense two-dimensional grid, as used by most map-based puzzles.
+///
+/// Coordinates are [`Point`]s with `x` growing to the right and `y` growing downwards, so `(0, 0)` is the top left
+/// character of the input. Cells are stored row by row.
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub},
+};
+
+/* -------------------------------------------------------------------------- */
+
+/// Coordinate of a cell, or an offset between two cells.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Distance to `other` when moving along rows and columns only.
+    #[must_use]
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The 4 orthogonal neighbours, in the order of [`Direction::ALL`].
+    #[must_use]
+    pub fn neighbours(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self + direction)
+    }
+
+    /// The 8 neighbours including diagonals, clockwise starting north.
+    #[must_use]
+    pub fn neighbours8(self) -> [Point; 8] {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .map(|(x, y)| self + Point::new(x, y))
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the 4 orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise starting north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Offset of a single step in this direction.
+    #[must_use]
+    pub const fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    #[must_use]
+    pub const fn reverse(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// `^`, `>`, `v` and `<` as well as `U`, `R`, `D` and `L`.
+    #[must_use]
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' | 'U' | 'N' => Some(Direction::North),
+            '>' | 'R' | 'E' => Some(Direction::East),
+            'v' | 'D' | 'S' => Some(Direction::South),
+            '<' | 'L' | 'W' => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A rectangular grid of cells.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Grid of the characters of the input, one row per line.
+    ///
+    /// # Panics
+    /// If the lines of the input differ in length.
+    #[must_use]
+    pub fn parse(input: &str) -> Self {
+        Self::parse_with(input, |c| c)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Grid of the input, one row per line, with every character converted by `f`.
+    ///
+    /// # Panics
+    /// If the lines of the input differ in length.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let line_width = cells.len() - len;
+
+            let width = *width.get_or_insert(line_width);
+            assert!(
+                line_width == width,
+                "row {y} has {line_width} cells, expected {width}"
+            );
+
+            height += 1;
+        }
+
+        Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        }
+    }
+
+    /// Grid of `width` columns filled row by row with `cells`.
+    ///
+    /// # Panics
+    /// If the cells do not fill the last row.
+    #[must_use]
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fit rows of {width}",
+            cells.len()
+        );
+        let height = cells.len() / width;
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid of `width` × `height` cells, all set to `value`.
+    #[must_use]
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// `true` if the point lies within the bounds of the grid.
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    /// The cell at `point`, `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// The cell at `point` on a grid that repeats infinitely in all directions.
+    #[must_use]
+    pub fn get_wrapping(&self, point: Point) -> &T {
+        let x = point.x.rem_euclid(self.width as i64);
+        let y = point.y.rem_euclid(self.height as i64);
+        &self[Point::new(x, y)]
+    }
+
+    /// The orthogonal neighbours of `point` that lie within the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours()
+            .into_iter()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The neighbours of `point` including diagonals that lie within the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours8()
+            .into_iter()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// All coordinates of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new((i % width) as i64, (i / width) as i64))
+    }
+
+    /// All cells of the grid with their coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Coordinate of the first cell, row by row, that matches the predicate.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// The cells of row `y`.
+    ///
+    /// # Panics
+    /// If the row is out of bounds.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    ///
+    /// # Panics
+    /// If the column is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Grid of the same size with every cell converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Grid mirrored along its main diagonal, rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Grid rotated by a quarter turn clockwise.
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// Grid rotated by a quarter turn counter-clockwise.
+    #[must_use]
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Grid of `width` × `height` cells, taking cell `(x, y)` from `source(x, y)` of this grid.
+    fn rearrange(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                let (sx, sy) = source(x, y);
+                cells.push(self.cells[sy * self.width + sx].clone());
+            }
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is out of bounds"))
+    }
+}
+
+/// Prints the grid back as text, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Grid, Point};
+
+    const INPUT: &str = "#..\n.#.\n..S\n..#\n";
+
+    #[test]
+    fn parses_grids() {
+        let grid = Grid::parse(INPUT);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 4);
+        assert_eq!(grid[Point::new(1, 1)], '#');
+        assert_eq!(grid.position(|c| *c == 'S'), Some(Point::new(2, 2)));
+        assert_eq!(grid.to_string(), INPUT.trim_end());
+
+        let walls = Grid::parse_with(INPUT, |c| c == '#');
+        assert_eq!(walls.iter().filter(|(_, wall)| **wall).count(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has 2 cells, expected 3")]
+    fn rejects_ragged_rows() {
+        Grid::parse("...\n..\n");
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = Grid::parse(INPUT);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(2, 3)), Some(&'#'));
+        assert_eq!(grid.get_wrapping(Point::new(-1, 4)), &'.');
+        assert_eq!(grid.get_wrapping(Point::new(-3, -4)), &'#');
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = Grid::parse(INPUT);
+        let corner: Vec<Point> = grid.neighbours(Point::ORIGIN).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point::ORIGIN).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(Point::new(1, 1) + Direction::North, Point::new(1, 0));
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left().reverse(), Direction::East);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = Grid::parse(INPUT);
+        assert_eq!(grid.row(2), &['.', '.', 'S']);
+        assert_eq!(grid.column(2).collect::<String>(), "..S#");
+        assert_eq!(grid.rows().count(), 4);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let grid = Grid::parse("ab\ncd\nef");
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "bdf\nace");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counter_clockwise(),
+            grid.clone()
+        );
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).row(0), &['A', 'B']);
+    }
+}
@@ -0,0 +1,185 @@
+/// Systems of linear equations, solved exactly with fractions instead of floating point.
+///
+/// Puzzles that reduce to a linear system usually have integer answers with huge coefficients, where `f64` loses
+/// precision and `i128` overflows on the way. Solving over [`Rational`] gives the exact answer or says why there is
+/// none.
+use crate::rational::Rational;
+
+/// How many solutions a system of equations has.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+    /// Exactly one, the value of every unknown.
+    Unique(Vec<Rational>),
+    /// Infinitely many, because only `rank` of the equations are independent.
+    /// `particular` is one of the solutions, with every unknown that is not pinned down set to zero.
+    Infinite {
+        particular: Vec<Rational>,
+        rank: usize,
+    },
+    /// None at all, the equations contradict each other.
+    Inconsistent,
+}
+
+impl Solution {
+    /// The values of the unknowns if there is exactly one solution.
+    #[must_use]
+    pub fn unique(self) -> Option<Vec<Rational>> {
+        match self {
+            Solution::Unique(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+/// Solve `matrix * x = rhs` for `x`, with one row of `matrix` per equation and one column per unknown.
+///
+/// There can be more equations than unknowns: redundant equations are fine as long as they agree with the others.
+///
+/// # Panics
+/// If the rows do not all have the same length, or `rhs` does not have one value per row.
+#[must_use]
+pub fn solve(matrix: &[Vec<Rational>], rhs: &[Rational]) -> Solution {
+    assert_eq!(matrix.len(), rhs.len(), "expected one value per equation");
+    let unknowns = columns(matrix);
+
+    let mut rows: Vec<Vec<Rational>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, value)| row.iter().chain([value]).cloned().collect())
+        .collect();
+    let pivots = row_reduce(&mut rows, unknowns);
+    let rank = pivots.len();
+
+    // below the pivots every coefficient is zero, so the value has to be as well.
+    if rows[rank..].iter().any(|row| !row[unknowns].is_zero()) {
+        return Solution::Inconsistent;
+    }
+
+    let mut particular = vec![Rational::zero(); unknowns];
+    for (row, &column) in pivots.iter().enumerate() {
+        particular[column] = rows[row][unknowns].clone();
+    }
+
+    if rank == unknowns {
+        Solution::Unique(particular)
+    } else {
+        Solution::Infinite { particular, rank }
+    }
+}
+
+/// Number of linearly independent rows of the matrix.
+///
+/// # Panics
+/// If the rows do not all have the same length.
+#[must_use]
+pub fn rank(matrix: &[Vec<Rational>]) -> usize {
+    let unknowns = columns(matrix);
+    row_reduce(&mut matrix.to_vec(), unknowns).len()
+}
+
+fn columns(matrix: &[Vec<Rational>]) -> usize {
+    let columns = matrix.first().map_or(0, Vec::len);
+    assert!(
+        matrix.iter().all(|row| row.len() == columns),
+        "expected every row to have {columns} columns"
+    );
+    columns
+}
+
+/// Gauss-Jordan elimination of the first `columns` columns, returning the column of the pivot in each row.
+///
+/// Afterwards the pivot rows come first, each pivot is one and is the only non-zero value in its column.
+/// Further columns, e.g. the right-hand side, are transformed along with the rest.
+fn row_reduce(rows: &mut [Vec<Rational>], columns: usize) -> Vec<usize> {
+    let mut pivots = vec![];
+
+    for column in 0..columns {
+        let row = pivots.len();
+        let Some(found) = (row..rows.len()).find(|&i| !rows[i][column].is_zero()) else {
+            // nothing left to eliminate in this column, its unknown is free.
+            continue;
+        };
+        rows.swap(row, found);
+
+        let pivot = rows[row][column].clone();
+        for value in &mut rows[row][column..] {
+            *value = &*value / &pivot;
+        }
+
+        for other in 0..rows.len() {
+            if other == row || rows[other][column].is_zero() {
+                continue;
+            }
+            let factor = rows[other][column].clone();
+            for k in column..rows[other].len() {
+                let delta = &factor * &rows[row][k];
+                rows[other][k] = &rows[other][k] - &delta;
+            }
+        }
+
+        pivots.push(column);
+        if pivots.len() == rows.len() {
+            break;
+        }
+    }
+
+    pivots
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{rank, solve, Solution};
+    use crate::rational::Rational;
+
+    fn matrix<const N: usize>(rows: &[[i64; N]]) -> Vec<Vec<Rational>> {
+        rows.iter()
+            .map(|row| row.iter().map(|&value| Rational::from(value)).collect())
+            .collect()
+    }
+
+    fn vector(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|&value| Rational::from(value)).collect()
+    }
+
+    #[test]
+    fn solves_square_systems() {
+        // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27
+        let a = matrix(&[[1, 1, 1], [0, 2, 5], [2, 5, -1]]);
+        let solution = solve(&a, &vector(&[6, -4, 27]));
+        assert_eq!(solution, Solution::Unique(vector(&[5, 3, -2])));
+
+        // 2x = 1, the answer is a fraction.
+        let solution = solve(&matrix(&[[2]]), &vector(&[1])).unique().unwrap();
+        assert_eq!(solution, vec![Rational::new(1, 2)]);
+    }
+
+    #[test]
+    fn solves_over_determined_systems() {
+        // the third equation is the sum of the first two.
+        let a = matrix(&[[1, 2], [3, -1], [4, 1]]);
+        assert_eq!(rank(&a), 2);
+        assert_eq!(
+            solve(&a, &vector(&[5, 1, 6])),
+            Solution::Unique(vector(&[1, 2]))
+        );
+        assert_eq!(solve(&a, &vector(&[5, 1, 7])), Solution::Inconsistent);
+    }
+
+    #[test]
+    fn detects_under_determined_systems() {
+        // x + y = 2, 2x + 2y = 4
+        let a = matrix(&[[1, 1], [2, 2]]);
+        assert_eq!(rank(&a), 1);
+        assert_eq!(
+            solve(&a, &vector(&[2, 4])),
+            Solution::Infinite {
+                particular: vector(&[2, 0]),
+                rank: 1,
+            }
+        );
+        assert_eq!(solve(&a, &vector(&[2, 5])), Solution::Inconsistent);
+        assert_eq!(solve(&a, &vector(&[2, 4])).unique(), None);
+    }
+}
@@ -1,40 +1,65 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, time, watch,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::PuzzleId;
 #[cfg(feature = "today")]
 use std::process;
 
+// `all` and `time` run solutions in-process, so their heap usage is counted by this binary.
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOC: advent_of_code::template::heap::CountingAlloc =
+    advent_of_code::template::heap::CountingAlloc;
+
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::commands::time;
+    use advent_of_code::template::{Day, PuzzleId, Year};
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+        },
+        Examples {
+            puzzle: PuzzleId,
+            overwrite: bool,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
             download: bool,
             overwrite: bool,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             dhat: bool,
+            heap: bool,
             submit: Option<u8>,
+            check: bool,
+            watch: bool,
+            test: bool,
+            variants: bool,
         },
         All {
+            year: Year,
             release: bool,
+            check: bool,
+            timeout: Option<Duration>,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            history: bool,
+            compare: Option<time::Compare>,
+            timeout: Option<Duration>,
         },
         #[cfg(feature = "today")]
         Today,
@@ -45,34 +70,53 @@ mod args {
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
+                check: args.contains("--check"),
+                timeout: parse_timeout(&mut args)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let history = args.contains("--history");
+                let compare = parse_compare(&mut args)?;
+                let timeout = parse_timeout(&mut args)?;
 
                 AppArguments::Time {
+                    year: parse_year(&mut args)?,
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    history,
+                    compare,
+                    timeout,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                puzzle: parse_puzzle(&mut args)?,
+                overwrite: args.contains("--overwrite"),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                heap: args.contains("--heap"),
+                check: args.contains("--check"),
+                watch: args.contains("--watch"),
+                test: args.contains("--test"),
+                variants: args.contains("--variants"),
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -93,6 +137,49 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// The year passed with `--year`, defaults to `AOC_YEAR`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year specified, pass `--year` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    /// The day passed as free argument, in the year passed with `--year`.
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        let day: Day = args.free_from_str()?;
+        Ok(PuzzleId::new(year, day))
+    }
+
+    /// Per-day wall-clock limit in seconds, e.g. `--timeout 2.5`.
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        let timeout: Option<f64> = args.opt_value_from_str("--timeout")?;
+        Ok(timeout.map(Duration::try_from_secs_f64).transpose()?)
+    }
+
+    /// `--compare` diffs against the stored timings, `--compare-file <path>` against another timings file.
+    fn parse_compare(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<time::Compare>, Box<dyn std::error::Error>> {
+        let file: Option<String> = args.opt_value_from_str("--compare-file")?;
+        if !args.contains("--compare") && file.is_none() {
+            return Ok(None);
+        }
+
+        let threshold: f64 = args.opt_value_from_str("--threshold")?.unwrap_or(5.0);
+
+        Ok(Some(time::Compare {
+            file,
+            threshold: threshold / 100.0,
+        }))
+    }
 }
 
 fn main() {
@@ -102,33 +189,60 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All {
+                year,
+                release,
+                check,
+                timeout,
+            } => all::handle(year, release, check, timeout),
+            AppArguments::Time {
+                year,
                 day,
+                all,
+                store,
+                history,
+                compare,
+                timeout,
+            } => time::handle(year, day, all, store, history, compare, timeout),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Examples { puzzle, overwrite } => examples::handle(puzzle, overwrite),
+            AppArguments::Scaffold {
+                puzzle,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(puzzle, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
+                    examples::handle(puzzle, false);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
+                heap,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                check,
+                watch,
+                test,
+                variants,
+            } => {
+                if watch {
+                    watch::handle(puzzle, release, test);
+                } else {
+                    solve::handle(puzzle, release, dhat, heap, submit, check, variants);
+                }
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match PuzzleId::today() {
+                    Some(puzzle) => {
+                        scaffold::handle(puzzle, false);
+                        download::handle(puzzle);
+                        examples::handle(puzzle, false);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
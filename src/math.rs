@@ -0,0 +1,222 @@
+/// Number theory for puzzles where several cycles have to line up, e.g. ghosts that each loop through a network.
+///
+/// Products of cycle lengths get large quickly, so everything here divides before it multiplies and reports or
+/// panics on overflow instead of wrapping around.
+use std::{
+    fmt::Debug,
+    ops::{Div, Rem},
+};
+
+/// The primitive integer types, signed and unsigned.
+pub trait Integer: Copy + Ord + Debug + Rem<Output = Self> + Div<Output = Self> {
+    const ZERO: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// The absolute value, which is the value itself for unsigned types.
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    (unsigned: $($unsigned:ty),*; signed: $($signed:ty),*) => {
+        $(
+            impl Integer for $unsigned {
+                const ZERO: Self = 0;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$unsigned>::checked_mul(self, other)
+                }
+
+                fn abs(self) -> Self {
+                    self
+                }
+            }
+        )*
+        $(
+            impl Integer for $signed {
+                const ZERO: Self = 0;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$signed>::checked_mul(self, other)
+                }
+
+                fn abs(self) -> Self {
+                    <$signed>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(unsigned: u8, u16, u32, u64, u128, usize; signed: i8, i16, i32, i64, i128, isize);
+
+/* -------------------------------------------------------------------------- */
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0.
+#[must_use]
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, never negative, `None` if it does not fit in `T`. It is 0 if either number is 0.
+#[must_use]
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a.abs() / gcd(a, b)).checked_mul(b.abs())
+}
+
+/// Least common multiple, never negative. It is 0 if either number is 0.
+///
+/// # Panics
+/// If the result does not fit in `T`.
+#[must_use]
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm of {a:?} and {b:?} overflows"))
+}
+
+/// Extended Euclidean algorithm, returns `(gcd, x, y)` such that `a * x + b * y == gcd`.
+#[must_use]
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, `None` if `a` and `modulus` are not coprime.
+///
+/// # Panics
+/// If `modulus` is not positive.
+#[must_use]
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "modulus must be positive, got {modulus}");
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Chinese remainder theorem: combine congruences `x ≡ residue (mod modulus)` into a single `(residue, modulus)`.
+///
+/// The moduli do not need to be coprime, the combined modulus is their least common multiple and the residue lies
+/// in `0..modulus`. Returns `None` if the congruences contradict each other. Without congruences, every number is a
+/// solution, which is `(0, 1)`.
+///
+/// # Panics
+/// If a modulus is not positive, or the combined modulus or an intermediate product does not fit in an `i128`. The
+/// moduli should fit in an `i64` to keep the intermediate products in range.
+#[must_use]
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let mut combined = (0, 1);
+
+    for (residue, modulus) in congruences {
+        assert!(modulus > 0, "modulus must be positive, got {modulus}");
+        let (r1, m1) = combined;
+        let r2 = residue.rem_euclid(modulus);
+
+        let g = gcd(m1, modulus);
+        if (r2 - r1) % g != 0 {
+            return None;
+        }
+
+        // solve r1 + m1 * k ≡ r2 (mod modulus) for k, after dividing everything by the common factor.
+        let m2 = modulus / g;
+        let inverse = mod_inverse(m1 / g, m2).expect("m1 / g and m2 are coprime");
+        let m = lcm(m1, modulus);
+        let overflow = || panic!("crt of {r1} mod {m1} and {r2} mod {modulus} overflows");
+
+        let k = ((r2 - r1) / g % m2)
+            .checked_mul(inverse)
+            .unwrap_or_else(overflow)
+            % m2;
+        let x = m1
+            .checked_mul(k)
+            .and_then(|x| x.checked_add(r1))
+            .unwrap_or_else(overflow);
+        combined = (x.rem_euclid(m), m);
+    }
+
+    Some(combined)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{checked_lcm, crt, extended_gcd, gcd, lcm, mod_inverse};
+
+    #[test]
+    fn computes_gcd_and_lcm() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(gcd(0usize, 7), 7);
+        assert_eq!(gcd(0u8, 0), 0);
+
+        assert_eq!(lcm(4usize, 6), 12);
+        assert_eq!(lcm(-4i32, 6), 12);
+        assert_eq!(lcm(0u64, 6), 0);
+        // the product overflows, the lcm does not.
+        let (a, b) = (3u64 << 40, 5u64 << 40);
+        assert_eq!(a.checked_mul(b), None);
+        assert_eq!(lcm(a, b), 15 << 40);
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn rejects_overflowing_lcm() {
+        let _ = lcm(u32::MAX, u32::MAX - 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn rejects_overflowing_congruences() {
+        // the combined modulus fits in an i128, the product of the residue and the inverse of 3 does not.
+        let p = (1 << 100) + 1;
+        assert_eq!(checked_lcm(3, p), Some(3 * p));
+        let _ = crt([(0, 3), (p - 1, p)]);
+    }
+
+    #[test]
+    fn inverts_modulo() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(2, 4), None);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // not coprime, but consistent.
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+
+        let (a, b) = (1_000_000_007, 998_244_353);
+        assert_eq!(
+            crt([(5, a), (7, b)]).map(|(r, m)| (r % a, r % b, m)),
+            Some((5, 7, a * b))
+        );
+    }
+}
@@ -0,0 +1,360 @@
+/// Sets of values stored as ranges, for puzzles where the values are far too many to handle one by one.
+///
+/// All ranges are half-open like [`std::ops::Range`], `start..end` contains `start` but not `end`.
+use std::ops::{Range, Sub};
+
+/// Overlap of two ranges, `None` if they do not overlap.
+#[must_use]
+pub fn intersect<T: Copy + Ord>(a: &Range<T>, b: &Range<T>) -> Option<Range<T>> {
+    let range = a.start.max(b.start)..a.end.min(b.end);
+    (range.start < range.end).then_some(range)
+}
+
+/// Parts of `a` before and after `b`, either of them `None` if it is empty.
+#[must_use]
+pub fn subtract<T: Copy + Ord>(a: &Range<T>, b: &Range<T>) -> (Option<Range<T>>, Option<Range<T>>) {
+    let before = a.start..a.end.min(b.start);
+    let after = a.start.max(b.end)..a.end;
+    (
+        (before.start < before.end).then_some(before),
+        (after.start < after.end).then_some(after),
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of values, stored as sorted ranges that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ranges of the set in ascending order.
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The smallest value of the set.
+    #[must_use]
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    /// Number of values in the set.
+    #[must_use]
+    pub fn size(&self) -> T
+    where
+        T: Sub<Output = T> + std::iter::Sum,
+    {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+
+    /// Add the values of a range to the set.
+    pub fn insert(&mut self, range: Range<T>) {
+        self.ranges.push(range);
+        self.normalise();
+    }
+
+    /// Values that are in either set.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    /// Values that are in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            if let Some(overlap) = intersect(a, b) {
+                ranges.push(overlap);
+            }
+            // move on from the range that ends first, the other one might still overlap the next.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Values of this set that are not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+
+        for range in &self.ranges {
+            let mut rest = Some(range.clone());
+
+            // skip the ranges of `other` that end before this one starts.
+            while other.ranges.get(j).is_some_and(|b| b.end <= range.start) {
+                j += 1;
+            }
+
+            for b in other.ranges[j..].iter().take_while(|b| b.start < range.end) {
+                let Some(current) = rest else {
+                    break;
+                };
+                let (before, after) = subtract(&current, b);
+                ranges.extend(before);
+                rest = after;
+            }
+
+            ranges.extend(rest);
+        }
+
+        Self { ranges }
+    }
+
+    /// Split the set into the values below `value` and the values from `value` on.
+    #[must_use]
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+
+        for range in &self.ranges {
+            if range.end <= value {
+                below.push(range.clone());
+            } else if range.start >= value {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..value);
+                above.push(value..range.end);
+            }
+        }
+
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Sort the ranges, drop empty ones and merge those that overlap or touch.
+    fn normalise(&mut self) {
+        self.ranges.retain(|range| range.start < range.end);
+        self.ranges.sort_unstable_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if last.end >= range.start => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        self.ranges = merged;
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self {
+            ranges: iter.into_iter().collect(),
+        };
+        set.normalise();
+        set
+    }
+}
+
+impl<T> IntoIterator for RangeSet<T> {
+    type Item = Range<T>;
+    type IntoIter = std::vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A box in `N` dimensions, with a range of values along each axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HyperRect<T, const N: usize> {
+    pub axes: [Range<T>; N],
+}
+
+impl<T: Copy + Ord, const N: usize> HyperRect<T, N> {
+    #[must_use]
+    pub fn new(axes: [Range<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    /// `true` if the box is empty along any of its axes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|range| range.start >= range.end)
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(range, value)| range.contains(&value))
+    }
+
+    /// Number of points in the box.
+    #[must_use]
+    pub fn volume(&self) -> u128
+    where
+        T: Into<i128>,
+    {
+        if self.is_empty() {
+            return 0;
+        }
+
+        self.axes
+            .iter()
+            .map(|range| range.end.into().abs_diff(range.start.into()))
+            .product()
+    }
+
+    /// Overlap of two boxes, `None` if they do not overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes.clone();
+        for (axis, range) in axes.iter_mut().enumerate() {
+            *range = intersect(range, &other.axes[axis])?;
+        }
+        Some(Self { axes })
+    }
+
+    /// Split the box along `axis` into the part below `value` and the part from `value` on.
+    /// Either of them is `None` if it is empty, so an empty box splits into nothing.
+    #[must_use]
+    pub fn split(&self, axis: usize, value: T) -> (Option<Self>, Option<Self>) {
+        // an earlier split can leave an axis empty or even inverted, which `clamp` does not accept.
+        if self.is_empty() {
+            return (None, None);
+        }
+
+        let range = &self.axes[axis];
+        let at = value.clamp(range.start, range.end);
+
+        let mut below = self.clone();
+        below.axes[axis] = range.start..at;
+        let mut above = self.clone();
+        above.axes[axis] = at..range.end;
+
+        (
+            Some(below).filter(|rect| !rect.is_empty()),
+            Some(above).filter(|rect| !rect.is_empty()),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{intersect, subtract, HyperRect, RangeSet};
+
+    #[test]
+    fn intersects_and_subtracts_ranges() {
+        assert_eq!(intersect(&(5..15), &(7..22)), Some(7..15));
+        assert_eq!(intersect(&(5..15), &(0..8)), Some(5..8));
+        assert_eq!(intersect(&(5..15), &(15..20)), None);
+
+        assert_eq!(subtract(&(1..10), &(3..7)), (Some(1..3), Some(7..10)));
+        assert_eq!(subtract(&(10..20), &(15..25)), (Some(10..15), None));
+        assert_eq!(subtract(&(10..20), &(5..15)), (None, Some(15..20)));
+        assert_eq!(subtract(&(10..20), &(30..40)), (Some(10..20), None));
+    }
+
+    #[test]
+    fn normalises_ranges() {
+        let set: RangeSet<i64> = [6..8, 2..5, 1..3, 9..9, 8..9].into_iter().collect();
+        assert_eq!(set.ranges(), &[1..5, 6..9]);
+        assert_eq!(set.min(), Some(1));
+        assert_eq!(set.size(), 7);
+        assert_eq!(set.contains(4), true);
+        assert_eq!(set.contains(5), false);
+        assert_eq!(set.contains(8), true);
+        assert_eq!(RangeSet::<i64>::new().min(), None);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a: RangeSet<i64> = [0..10, 20..30].into_iter().collect();
+        let b: RangeSet<i64> = [5..25, 28..40].into_iter().collect();
+
+        assert_eq!(a.union(&b).ranges(), &[0..40]);
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+        assert_eq!(a.difference(&RangeSet::new()), a);
+    }
+
+    #[test]
+    fn splits_sets() {
+        let set: RangeSet<i64> = [0..10, 20..30].into_iter().collect();
+        let (below, above) = set.split_at(25);
+        assert_eq!(below.ranges(), &[0..10, 20..25]);
+        assert_eq!(above.ranges(), &[25..30]);
+
+        let mut set = set;
+        set.insert(10..20);
+        assert_eq!(set.ranges(), &[0..30]);
+    }
+
+    #[test]
+    fn splits_hyper_rects() {
+        let rect = HyperRect::new([1u16..4001, 1..4001, 1..4001, 1..4001]);
+        assert_eq!(rect.volume(), 4000u128.pow(4));
+        assert_eq!(rect.contains([1, 2, 3, 4000]), true);
+
+        let (below, above) = rect.split(1, 2091);
+        let (below, above) = (below.unwrap(), above.unwrap());
+        assert_eq!(below.axes[1], 1..2091);
+        assert_eq!(above.axes[1], 2091..4001);
+        assert_eq!(below.volume() + above.volume(), rect.volume());
+
+        assert_eq!(rect.split(0, 1).0, None);
+        assert_eq!(rect.split(0, 5000).1, None);
+
+        let other = HyperRect::new([0u16..2, 0..2, 0..2, 3000..5000]);
+        assert_eq!(rect.intersection(&other).unwrap().volume(), 1001);
+        assert_eq!(HyperRect::new([0i64..0, 0..5]).volume(), 0);
+    }
+
+    #[test]
+    fn splits_exhausted_hyper_rects() {
+        // the axis being split or another one may have run out.
+        let exhausted = HyperRect::new([1u16..4001, 2091..2091]);
+        assert_eq!(exhausted.split(1, 1000), (None, None));
+        assert_eq!(exhausted.split(0, 1000), (None, None));
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let inverted = HyperRect::new([1u16..4001, 3000..2000]);
+        assert_eq!(inverted.split(1, 2500), (None, None));
+        assert_eq!(inverted.split(0, 1000), (None, None));
+    }
+}
@@ -0,0 +1,618 @@
+/// Integers and fractions of any size, for puzzles whose answers are exact but whose intermediate values do not fit
+/// in an `i128`, e.g. the determinants of a system of equations with huge coefficients.
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// A signed integer of any size.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    /// Limbs from least to most significant, without leading zeros. Zero has no limbs.
+    magnitude: Vec<u64>,
+}
+
+impl BigInt {
+    fn new(negative: bool, mut magnitude: Vec<u64>) -> Self {
+        trim(&mut magnitude);
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    fn from_u128(negative: bool, value: u128) -> Self {
+        Self::new(negative, vec![value as u64, (value >> 64) as u64])
+    }
+
+    #[must_use]
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    #[must_use]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self::new(false, self.magnitude.clone())
+    }
+
+    /// The value as an `i128`, `None` if it does not fit.
+    #[must_use]
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = to_u128(&self.magnitude)?;
+        if self.negative {
+            // `i128::MIN` has no positive counterpart, so negate after the conversion.
+            (magnitude <= 1 << 127).then(|| (magnitude as i128).wrapping_neg())
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// Quotient rounded towards zero and the remainder, which has the sign of `self`.
+    ///
+    /// # Panics
+    /// If `divisor` is zero.
+    #[must_use]
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by zero");
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &divisor.magnitude);
+        (
+            Self::new(self.negative != divisor.negative, quotient),
+            Self::new(self.negative, remainder),
+        )
+    }
+
+    /// Greatest common divisor, never negative. The gcd of zero and zero is zero.
+    #[must_use]
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.magnitude.clone(), other.magnitude.clone());
+        while !b.is_empty() {
+            if let (Some(x), Some(y)) = (to_u128(&a), to_u128(&b)) {
+                return Self::from_u128(false, crate::math::gcd(x, y));
+            }
+            let (_, remainder) = div_rem_magnitude(&a, &b);
+            (a, b) = (b, remainder);
+        }
+        Self::new(false, a)
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for BigInt {
+                fn from(value: $type) -> Self {
+                    let value = value as i128;
+                    Self::from_u128(value < 0, value.unsigned_abs())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(i32, i64, i128, isize, u32, u64, usize);
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // peel off 19 decimal digits at a time, the most that fit in a limb.
+        const CHUNK: u64 = 10_u64.pow(19);
+        let mut chunks = vec![];
+        let mut rest = self.magnitude.clone();
+        while !rest.is_empty() {
+            let (quotient, remainder) = div_rem_magnitude(&rest, &[CHUNK]);
+            chunks.push(remainder.first().copied().unwrap_or(0));
+            rest = quotient;
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap_or(&0))?;
+        for chunk in chunks {
+            write!(f, "{chunk:019}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(
+                self.negative,
+                add_magnitude(&self.magnitude, &other.magnitude),
+            );
+        }
+        // opposite signs, subtract the smaller magnitude from the larger one.
+        match cmp_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::new(
+                other.negative,
+                sub_magnitude(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::new(
+                self.negative,
+                sub_magnitude(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            mul_magnitude(&self.magnitude, &other.magnitude),
+        )
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+/// Implement an operator for owned values in terms of the one for references.
+macro_rules! forward_binop {
+    ($type:ty, $($trait:ident :: $method:ident),*) => {
+        $(
+            impl $trait for $type {
+                type Output = $type;
+
+                fn $method(self, other: $type) -> $type {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&$type> for $type {
+                type Output = $type;
+
+                fn $method(self, other: &$type) -> $type {
+                    (&self).$method(other)
+                }
+            }
+        )*
+    };
+}
+
+forward_binop!(BigInt, Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+
+/* -------------------------------------------------------------------------- */
+
+fn trim(magnitude: &mut Vec<u64>) {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+}
+
+fn to_u128(magnitude: &[u64]) -> Option<u128> {
+    match magnitude {
+        [] => Some(0),
+        [low] => Some(*low as u128),
+        [low, high] => Some((*high as u128) << 64 | *low as u128),
+        _ => None,
+    }
+}
+
+fn from_u128(value: u128) -> Vec<u64> {
+    let mut magnitude = vec![value as u64, (value >> 64) as u64];
+    trim(&mut magnitude);
+    magnitude
+}
+
+fn cmp_magnitude(a: &[u64], b: &[u64]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = false;
+
+    for (i, &limb) in long.iter().enumerate() {
+        let (partial, overflow_a) = limb.overflowing_add(short.get(i).copied().unwrap_or(0));
+        let (partial, overflow_b) = partial.overflowing_add(carry as u64);
+        sum.push(partial);
+        carry = overflow_a || overflow_b;
+    }
+    if carry {
+        sum.push(1);
+    }
+    sum
+}
+
+/// `a - b`, where `a` must not be smaller than `b`.
+fn sub_magnitude(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = false;
+
+    for (i, &limb) in a.iter().enumerate() {
+        let (partial, overflow_a) = limb.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (partial, overflow_b) = partial.overflowing_sub(borrow as u64);
+        difference.push(partial);
+        borrow = overflow_a || overflow_b;
+    }
+    debug_assert!(!borrow, "subtracted a larger magnitude");
+    trim(&mut difference);
+    difference
+}
+
+fn mul_magnitude(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let mut product = vec![0; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let partial = x as u128 * y as u128 + product[i + j] as u128 + carry;
+            product[i + j] = partial as u64;
+            carry = partial >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+    trim(&mut product);
+    product
+}
+
+fn div_rem_magnitude(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    if cmp_magnitude(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    if let (Some(x), Some(y)) = (to_u128(a), to_u128(b)) {
+        return (from_u128(x / y), from_u128(x % y));
+    }
+
+    if let [divisor] = b {
+        // long division by a single limb.
+        let divisor = *divisor as u128;
+        let mut quotient = vec![0; a.len()];
+        let mut remainder = 0u128;
+        for i in (0..a.len()).rev() {
+            let current = remainder << 64 | a[i] as u128;
+            quotient[i] = (current / divisor) as u64;
+            remainder = current % divisor;
+        }
+        trim(&mut quotient);
+        return (quotient, from_u128(remainder));
+    }
+
+    // binary long division, slow but simple. The numbers in puzzles are only a few limbs long.
+    let mut quotient = vec![0; a.len()];
+    let mut remainder: Vec<u64> = vec![];
+    for bit in (0..a.len() * 64).rev() {
+        // remainder = remainder * 2 + the next bit of `a`
+        let mut carry = (a[bit / 64] >> (bit % 64)) & 1;
+        for limb in &mut remainder {
+            let next = *limb >> 63;
+            *limb = *limb << 1 | carry;
+            carry = next;
+        }
+        if carry > 0 {
+            remainder.push(carry);
+        }
+
+        if cmp_magnitude(&remainder, b) != Ordering::Less {
+            remainder = sub_magnitude(&remainder, b);
+            quotient[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+    trim(&mut quotient);
+    (quotient, remainder)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A fraction of big integers, always in lowest terms with a positive denominator.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl Rational {
+    /// # Panics
+    /// If `denominator` is zero.
+    #[must_use]
+    pub fn new(numerator: impl Into<BigInt>, denominator: impl Into<BigInt>) -> Self {
+        let (numerator, denominator) = (numerator.into(), denominator.into());
+        assert!(!denominator.is_zero(), "denominator must not be zero");
+
+        let divisor = numerator.gcd(&denominator);
+        let (mut numerator, mut denominator) = (&numerator / &divisor, &denominator / &divisor);
+        if denominator.is_negative() {
+            (numerator, denominator) = (-numerator, -denominator);
+        }
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[must_use]
+    pub fn zero() -> Self {
+        Self::from(0)
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    #[must_use]
+    pub fn is_negative(&self) -> bool {
+        self.numerator.is_negative()
+    }
+
+    #[must_use]
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    #[must_use]
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    /// The value as an integer, `None` if it is a proper fraction.
+    #[must_use]
+    pub fn to_integer(&self) -> Option<&BigInt> {
+        (self.denominator == BigInt::from(1)).then_some(&self.numerator)
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(value: BigInt) -> Self {
+        Self {
+            numerator: value,
+            denominator: BigInt::from(1),
+        }
+    }
+}
+
+macro_rules! impl_rational_from_primitive {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Rational {
+                fn from(value: $type) -> Self {
+                    Self::from(BigInt::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_rational_from_primitive!(i32, i64, i128, isize, u32, u64, usize);
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // both denominators are positive, so cross multiplying keeps the order.
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_integer() {
+            Some(integer) => write!(f, "{integer}"),
+            None => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -&self.numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        -&self
+    }
+}
+
+impl Add for &Rational {
+    type Output = Rational;
+
+    fn add(self, other: &Rational) -> Rational {
+        Rational::new(
+            &self.numerator * &other.denominator + &other.numerator * &self.denominator,
+            &self.denominator * &other.denominator,
+        )
+    }
+}
+
+impl Sub for &Rational {
+    type Output = Rational;
+
+    fn sub(self, other: &Rational) -> Rational {
+        self + &-other
+    }
+}
+
+impl Mul for &Rational {
+    type Output = Rational;
+
+    fn mul(self, other: &Rational) -> Rational {
+        Rational::new(
+            &self.numerator * &other.numerator,
+            &self.denominator * &other.denominator,
+        )
+    }
+}
+
+impl Div for &Rational {
+    type Output = Rational;
+
+    /// # Panics
+    /// If `other` is zero.
+    fn div(self, other: &Rational) -> Rational {
+        assert!(!other.is_zero(), "division by zero");
+        Rational::new(
+            &self.numerator * &other.denominator,
+            &self.denominator * &other.numerator,
+        )
+    }
+}
+
+forward_binop!(Rational, Add::add, Sub::sub, Mul::mul, Div::div);
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BigInt, Rational};
+
+    #[test]
+    fn computes_with_big_integers() {
+        let big = BigInt::from(u64::MAX) * BigInt::from(u64::MAX) * BigInt::from(1_000_000_007);
+        assert_eq!(
+            big.to_string(),
+            "340282369302915031873050363269716943215443757575"
+        );
+        assert_eq!(big.to_i128(), None);
+        assert_eq!(
+            (&big / &BigInt::from(u64::MAX)) / BigInt::from(u64::MAX),
+            BigInt::from(1_000_000_007)
+        );
+        assert_eq!((&big - &big).is_zero(), true);
+        assert_eq!((-&big + BigInt::from(1)).is_negative(), true);
+
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(BigInt::from(-42).to_string(), "-42");
+        assert!(BigInt::from(-3) < BigInt::from(2));
+        assert!(-&big < BigInt::from(i128::MIN));
+    }
+
+    #[test]
+    fn divides_big_integers() {
+        let (quotient, remainder) = BigInt::from(-7).div_rem(&BigInt::from(2));
+        assert_eq!((quotient, remainder), (BigInt::from(-3), BigInt::from(-1)));
+
+        let a = BigInt::from(i128::MAX) * BigInt::from(12_345);
+        let b = BigInt::from(u128::MAX as i128 >> 3) * BigInt::from(3);
+        let (quotient, remainder) = a.div_rem(&b);
+        assert_eq!(&quotient * &b + remainder, a);
+
+        let common = BigInt::from(i128::MAX - 24);
+        let gcd = (&common * &BigInt::from(6)).gcd(&(&common * &BigInt::from(-15)));
+        assert_eq!(gcd, &common * &BigInt::from(3));
+    }
+
+    #[test]
+    fn computes_with_fractions() {
+        let third = Rational::new(1, 3);
+        assert_eq!(Rational::new(-4, -12), third);
+        assert_eq!(Rational::new(2, -4).to_string(), "-1/2");
+        assert_eq!((&third + &Rational::new(1, 6)).to_string(), "1/2");
+        assert_eq!((&third - &Rational::from(1)).to_string(), "-2/3");
+        assert_eq!(
+            (&third * &Rational::from(6)).to_integer(),
+            Some(&BigInt::from(2))
+        );
+        assert_eq!((&third / &Rational::new(2, 3)).to_string(), "1/2");
+        assert_eq!(third.to_integer(), None);
+        assert!(Rational::new(-1, 2) < third);
+        assert!(Rational::new(1, 3) > Rational::new(3, 10));
+    }
+}
@@ -0,0 +1,351 @@
+/// Graph searches over any state type, e.g. a grid position or a position together with a direction.
+///
+/// The graph is never built up front: every search starts at a state and asks a successor function for the states
+/// that can be reached from it. States need to be hashable so that the search can remember where it has been.
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The shortest path that a search found to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// States from the start to the goal, both included.
+    pub states: Vec<S>,
+    /// Sum of the costs along the path, the number of steps for a breadth-first search.
+    pub cost: C,
+    /// Number of states that were expanded before the goal was reached.
+    pub visited: usize,
+}
+
+impl<S, C> Path<S, C> {
+    /// The state at the end of the path.
+    ///
+    /// # Panics
+    /// Never, a path contains at least the start.
+    #[must_use]
+    pub fn goal(&self) -> &S {
+        self.states
+            .last()
+            .expect("a path contains at least the start")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Breadth-first search, for graphs where every step has the same cost.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut explored = Explored::new(start, 0);
+    let goal = breadth_first(&mut explored, &mut successors, &mut is_goal)?;
+    Some(explored.path(goal))
+}
+
+/// Every state that can be reached from `start`, with the number of steps of its shortest path.
+pub fn distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut explored = Explored::new(start, 0);
+    breadth_first(&mut explored, &mut successors, &mut |_| false);
+    explored
+        .nodes
+        .into_iter()
+        .map(|(state, cost, _)| (state, cost))
+        .collect()
+}
+
+/// Every state that can be reached from `start`, including the start itself.
+pub fn reachable<S, I>(start: S, successors: impl FnMut(&S) -> I) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    distances(start, successors).into_keys().collect()
+}
+
+/// Expand states in the order they were reached until the goal is found, returning its node.
+fn breadth_first<S, I>(
+    explored: &mut Explored<S, usize>,
+    successors: &mut impl FnMut(&S) -> I,
+    is_goal: &mut impl FnMut(&S) -> bool,
+) -> Option<usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        explored.visited += 1;
+        let (state, cost, _) = &explored.nodes[i];
+        if is_goal(state) {
+            return Some(i);
+        }
+
+        let cost = cost + 1;
+        for next in successors(state) {
+            if let Some(j) = explored.relax(next, cost, i) {
+                queue.push_back(j);
+            }
+        }
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Dijkstra's algorithm, the successor function returns every next state with the cost of the step to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search, Dijkstra's algorithm guided by an estimate of the remaining cost to the goal.
+///
+/// The path is only guaranteed to be the shortest if the heuristic never overestimates the remaining cost.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let estimate = heuristic(&start);
+    let mut explored = Explored::new(start, C::default());
+    let mut open = BinaryHeap::from([Reverse((estimate, C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, i))) = open.pop() {
+        let (state, best, _) = &explored.nodes[i];
+        // the state was reached more cheaply after this entry was queued.
+        if cost > *best {
+            continue;
+        }
+
+        explored.visited += 1;
+        if is_goal(state) {
+            return Some(explored.path(i));
+        }
+
+        for (next, step) in successors(state) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(j) = explored.relax(next, next_cost, i) {
+                open.push(Reverse((estimate, next_cost, j)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm with a bucket queue instead of a binary heap, also known as Dial's algorithm.
+///
+/// States are queued in one bucket per total cost, which is faster than a heap when the cost of a step is a small
+/// integer, e.g. the digit of a grid cell. The memory used grows with the cost of the path.
+pub fn dijkstra_buckets<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut explored = Explored::new(start, 0);
+    let mut buckets: Vec<Vec<usize>> = vec![vec![0]];
+    let mut queued = 1;
+    let mut cost = 0;
+
+    while queued > 0 {
+        let Some(i) = buckets[cost].pop() else {
+            cost += 1;
+            continue;
+        };
+        queued -= 1;
+
+        let (state, best, _) = &explored.nodes[i];
+        // the state was reached more cheaply after this entry was queued.
+        if cost > *best {
+            continue;
+        }
+
+        explored.visited += 1;
+        if is_goal(state) {
+            return Some(explored.path(i));
+        }
+
+        for (next, step) in successors(state) {
+            let next_cost = cost + step;
+            if let Some(j) = explored.relax(next, next_cost, i) {
+                if buckets.len() <= next_cost {
+                    buckets.resize_with(next_cost + 1, Vec::new);
+                }
+                buckets[next_cost].push(j);
+                queued += 1;
+            }
+        }
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The states a search has reached, with the cheapest known cost and the state it was reached from.
+struct Explored<S, C> {
+    index: HashMap<S, usize>,
+    nodes: Vec<(S, C, Option<usize>)>,
+    visited: usize,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Explored<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            index: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![(start, cost, None)],
+            visited: 0,
+        }
+    }
+
+    /// Record that `state` can be reached from node `parent` at `cost`.
+    /// Returns the node of the state if this is cheaper than any path to it seen before.
+    fn relax(&mut self, state: S, cost: C, parent: usize) -> Option<usize> {
+        match self.index.get(&state) {
+            Some(&i) if self.nodes[i].1 <= cost => None,
+            Some(&i) => {
+                self.nodes[i].1 = cost;
+                self.nodes[i].2 = Some(parent);
+                Some(i)
+            }
+            None => {
+                let i = self.nodes.len();
+                self.index.insert(state.clone(), i);
+                self.nodes.push((state, cost, Some(parent)));
+                Some(i)
+            }
+        }
+    }
+
+    /// Walk back from node `goal` to the start.
+    fn path(&self, goal: usize) -> Path<S, C> {
+        let mut states = vec![];
+        let mut node = Some(goal);
+        while let Some(i) = node {
+            states.push(self.nodes[i].0.clone());
+            node = self.nodes[i].2;
+        }
+        states.reverse();
+
+        Path {
+            states,
+            cost: self.nodes[goal].1,
+            visited: self.visited,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra, dijkstra_buckets, distances, reachable};
+    use crate::grid::{Grid, Point};
+
+    const MAZE: &str = "S.#.....\n#...##.#\n#.#.#...\n.##...#E";
+
+    fn open_neighbours(grid: &Grid<char>, point: Point) -> Vec<Point> {
+        grid.neighbours(point)
+            .filter(|next| grid[*next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn finds_shortest_paths_breadth_first() {
+        let grid = Grid::parse(MAZE);
+        let start = grid.position(|c| *c == 'S').unwrap();
+        let end = grid.position(|c| *c == 'E').unwrap();
+
+        let path = bfs(start, |p| open_neighbours(&grid, *p), |p| *p == end).unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.states.len(), 13);
+        assert_eq!(path.states[0], start);
+        assert_eq!(*path.goal(), end);
+        assert!(path
+            .states
+            .windows(2)
+            .all(|step| step[0].manhattan(step[1]) == 1));
+
+        assert_eq!(bfs(start, |p| open_neighbours(&grid, *p), |_| false), None);
+    }
+
+    #[test]
+    fn finds_reachable_states() {
+        let grid = Grid::parse(MAZE);
+        let open = grid.iter().filter(|(_, c)| **c != '#').count();
+
+        let reached = reachable(Point::ORIGIN, |p| open_neighbours(&grid, *p));
+        // the bottom left corner is walled in.
+        assert_eq!(reached.len(), open - 1);
+        assert_eq!(reached.contains(&Point::new(0, 3)), false);
+
+        let steps = distances(Point::ORIGIN, |p| open_neighbours(&grid, *p));
+        assert_eq!(steps[&Point::ORIGIN], 0);
+        assert_eq!(steps[&Point::new(7, 3)], 12);
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        let grid = Grid::parse_with("1163\n1381\n2136\n3611", |c| {
+            c.to_digit(10).unwrap() as usize
+        });
+        let end = Point::new(3, 3);
+        let successors = |p: &Point| {
+            grid.neighbours(*p)
+                .map(|next| (next, grid[next]))
+                .collect::<Vec<_>>()
+        };
+
+        let heap = dijkstra(Point::ORIGIN, successors, |p| *p == end).unwrap();
+        let buckets = dijkstra_buckets(Point::ORIGIN, successors, |p| *p == end).unwrap();
+        let guided = astar(
+            Point::ORIGIN,
+            successors,
+            |p| p.manhattan(end) as usize,
+            |p| *p == end,
+        )
+        .unwrap();
+
+        assert_eq!(heap.cost, 9);
+        assert_eq!(buckets.cost, 9);
+        assert_eq!(guided.cost, 9);
+        assert_eq!(
+            heap.states.iter().skip(1).map(|p| grid[*p]).sum::<usize>(),
+            9
+        );
+        assert!(guided.visited <= heap.visited);
+    }
+}
@@ -0,0 +1,303 @@
+/// Stores the accepted answers of solved days, so that solutions can be verified after a refactor.
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    puzzle_id::{insert_puzzle_json, puzzle_from_json},
+    runner::{PartResult, PartStatus},
+    PuzzleId, ANSI_BOLD, ANSI_RESET,
+};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Represents the accepted answers for a single puzzle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    pub puzzle: PuzzleId,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+/// Outcome of comparing a part's result against its accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckOutcome {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    ///
+    /// A file that cannot be read or parsed is an error, checking against it would report every part as missing and
+    /// storing it again would drop the recorded answers.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(ANSWERS_FILE_PATH) {
+            Ok(json) => Answers::try_from(json),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// The accepted answer for a part, if known.
+    pub fn get(&self, puzzle: PuzzleId, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.puzzle == puzzle)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Record the accepted answer for a part, overwriting a previous answer.
+    pub fn set(&mut self, puzzle: PuzzleId, part: u8, value: &str) {
+        let index = match self.data.iter().position(|a| a.puzzle == puzzle) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    puzzle,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.puzzle);
+                self.data.iter().position(|a| a.puzzle == puzzle).unwrap()
+            }
+        };
+
+        let answer = &mut self.data[index];
+        match part {
+            1 => answer.part_1 = Some(value.into()),
+            2 => answer.part_2 = Some(value.into()),
+            _ => {}
+        }
+    }
+
+    /// Compare the result of a part against the accepted answer.
+    pub fn check(&self, puzzle: PuzzleId, result: &PartResult) -> CheckOutcome {
+        let Some(expected) = self.get(puzzle, result.part) else {
+            return CheckOutcome::Missing;
+        };
+
+        match (&result.status, &result.answer) {
+            (PartStatus::Solved, Some(answer)) if answer == expected => CheckOutcome::Pass,
+            _ => CheckOutcome::Fail {
+                expected: expected.into(),
+            },
+        }
+    }
+
+    /// Print the outcome of every part and return `false` if any part did not match its accepted answer.
+    pub fn print_check(&self, puzzle: PuzzleId, results: &[PartResult]) -> bool {
+        let mut passed = true;
+
+        for result in results.iter().filter(|result| !result.is_parse()) {
+            let outcome = match self.check(puzzle, result) {
+                CheckOutcome::Pass => "✔ pass".to_string(),
+                CheckOutcome::Missing => "? missing".to_string(),
+                CheckOutcome::Fail { expected } => {
+                    passed = false;
+                    format!("{ANSI_BOLD}✖ fail{ANSI_RESET} (expected {expected})")
+                }
+            };
+            println!("Check part {}: {outcome}", result.part);
+        }
+
+        passed
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        insert_puzzle_json(&mut map, value.puzzle);
+
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match part {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let puzzle = puzzle_from_json(json, "answer")?;
+
+        // answers may be written by hand, so numbers are accepted as well.
+        let part = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::String(x)) => Ok(Some(x.clone())),
+            Some(JsonValue::Number(x)) => Ok(Some(x.to_string())),
+            Some(_) => Err(format!(
+                "Expected answer.{key} to be null, string or number."
+            )),
+        };
+
+        Ok(Answer {
+            puzzle,
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Answers, CheckOutcome};
+    use crate::{
+        puzzle,
+        template::runner::{BenchStats, PartResult, PartStatus},
+    };
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.map(Into::into),
+            error: None,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            stats: BenchStats::single(Duration::ZERO),
+            heap: None,
+        }
+    }
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "data": [{ "year": "2023", "day": "05", "part_1": "35", "part_2": 46 }, { "year": "2023", "day": "01", "part_1": null }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(puzzle!(2023, 5), 1), Some("35"));
+        assert_eq!(answers.get(puzzle!(2023, 5), 2), Some("46"));
+        assert_eq!(answers.get(puzzle!(2023, 1), 1), None);
+        assert_eq!(answers.get(puzzle!(2023, 1), 2), None);
+        assert_eq!(answers.get(puzzle!(2023, 2), 1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "year": "2023", "day": "05", "part_1": [] }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.set(puzzle!(2023, 3), 2, "467835");
+        answers.set(puzzle!(2023, 1), 1, "209");
+        answers.set(puzzle!(2023, 3), 1, "4361");
+        let json = tinyjson::JsonValue::from(answers.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.data, answers.data);
+        assert_eq!(parsed.data[0].puzzle, puzzle!(2023, 1));
+        assert_eq!(parsed.get(puzzle!(2023, 3), 1), Some("4361"));
+    }
+
+    #[test]
+    fn checks_results() {
+        let mut answers = Answers::default();
+        answers.set(puzzle!(2023, 1), 1, "209");
+        answers.set(puzzle!(2023, 1), 2, "281");
+
+        assert_eq!(
+            answers.check(puzzle!(2023, 1), &result(1, Some("209"))),
+            CheckOutcome::Pass
+        );
+        assert_eq!(
+            answers.check(puzzle!(2023, 1), &result(2, Some("280"))),
+            CheckOutcome::Fail {
+                expected: "281".into()
+            }
+        );
+        assert_eq!(
+            answers.check(puzzle!(2023, 1), &result(2, None)),
+            CheckOutcome::Fail {
+                expected: "281".into()
+            }
+        );
+        assert_eq!(
+            answers.check(puzzle!(2023, 2), &result(1, Some("1"))),
+            CheckOutcome::Missing
+        );
+    }
+}
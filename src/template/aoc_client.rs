@@ -0,0 +1,515 @@
+/// Built-in client for the advent of code website.
+/// Responses are cached on disk, the base URL can be pointed to a local server for testing.
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{submissions::Verdict, PuzzleId};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static CACHE_DIR: &str = "data/cache";
+
+/// Identifies the template to the puzzle server, as requested by its maintainer.
+static USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/JALabba/advent-2023-rust)"
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    Http(u16, String),
+    Transport(String),
+    IO(io::Error),
+}
+
+impl std::fmt::Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session token found. Set `AOC_SESSION` or create the file `~/.adventofcode.session`."
+            ),
+            AocClientError::Http(status, message) => {
+                write!(f, "server responded with status {status}: {message}")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+pub struct AocClient {
+    pub base_url: String,
+    pub session: String,
+    pub cache_dir: PathBuf,
+}
+
+impl AocClient {
+    /// Create a client from the environment:
+    ///  1. `AOC_SESSION` holds the session token, otherwise it is read from the file in `AOC_SESSION_FILE` or `~/.adventofcode.session`.
+    ///  2. `AOC_BASE_URL` optionally overrides the address of the puzzle server.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: read_session().ok_or(AocClientError::MissingSession)?,
+            cache_dir: PathBuf::from(CACHE_DIR),
+        })
+    }
+
+    /// Download the input and puzzle description of a puzzle to the given paths.
+    /// The input never changes and is served from the cache, the description is always refreshed.
+    pub fn download(
+        &self,
+        puzzle: PuzzleId,
+        input_path: &str,
+        puzzle_path: &str,
+    ) -> Result<(), AocClientError> {
+        let input = self.cached("input.txt", puzzle, false, || {
+            self.get(&format!("{}/input", puzzle_url(puzzle)))
+        })?;
+        fs::write(input_path, input)?;
+
+        let description = self.cached("puzzle.html", puzzle, true, || {
+            self.get(&puzzle_url(puzzle))
+        })?;
+        fs::write(puzzle_path, html_to_markdown(&description))?;
+
+        Ok(())
+    }
+
+    /// The description of a puzzle as markdown.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let description = self.cached("puzzle.html", puzzle, false, || {
+            self.get(&puzzle_url(puzzle))
+        })?;
+        Ok(html_to_markdown(&description))
+    }
+
+    /// Submit an answer and return the message of the server.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let response = ureq::post(&format!("{}{}/answer", self.base_url, puzzle_url(puzzle)))
+            .set("User-Agent", USER_AGENT)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let message = html_to_text(&extract_articles(&read_response(response)?));
+
+        // the description of part two is only revealed after part one was solved.
+        if Verdict::parse(&message) == Verdict::Correct {
+            let _ = fs::remove_file(self.cache_path(puzzle, "puzzle.html"));
+        }
+
+        Ok(message)
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        let response = ureq::get(&format!("{}{path}", self.base_url))
+            .set("User-Agent", USER_AGENT)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(response)
+    }
+
+    fn cache_path(&self, puzzle: PuzzleId, name: &str) -> PathBuf {
+        self.cache_dir
+            .join(puzzle.year.to_string())
+            .join(puzzle.day.to_string())
+            .join(name)
+    }
+
+    /// Return the cached response for `name`, or fetch and cache it.
+    fn cached(
+        &self,
+        name: &str,
+        puzzle: PuzzleId,
+        refresh: bool,
+        fetch: impl FnOnce() -> Result<String, AocClientError>,
+    ) -> Result<String, AocClientError> {
+        let path = self.cache_path(puzzle, name);
+
+        if !refresh {
+            if let Ok(cached) = fs::read_to_string(&path) {
+                return Ok(cached);
+            }
+        }
+
+        let response = fetch()?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, &response)?;
+
+        Ok(response)
+    }
+}
+
+/// Path of a puzzle on the server, e.g. `/2023/day/1`.
+fn puzzle_url(puzzle: PuzzleId) -> String {
+    format!("/{}/day/{}", puzzle.year, puzzle.day.into_inner())
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session.trim().to_string()).filter(|x| !x.is_empty());
+    }
+
+    let path = match env::var("AOC_SESSION_FILE") {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => Path::new(&env::var("HOME").ok()?).join(".adventofcode.session"),
+    };
+
+    let session = fs::read_to_string(path).ok()?;
+    Some(session.trim().to_string()).filter(|x| !x.is_empty())
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            let message = body.lines().next().unwrap_or_default().trim().to_string();
+            Err(AocClientError::Http(status, message))
+        }
+        Err(e) => Err(AocClientError::Transport(e.to_string())),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The contents of all `<article>` elements, which hold the puzzle description and server messages.
+fn extract_articles(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end..];
+    }
+
+    articles.join("\n")
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Split html into tags and text, yielding `(is_tag, content)`.
+fn tokenize(html: &str) -> Vec<(bool, &str)> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if rest.starts_with('<') {
+            let end = rest.find('>').map_or(rest.len(), |x| x + 1);
+            tokens.push((true, &rest[..end]));
+            rest = &rest[end..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push((false, &rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+fn tag_name(tag: &str) -> &str {
+    tag.trim_start_matches('<')
+        .trim_end_matches('>')
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let (_, rest) = tag.split_once(&format!("{name}=\""))?;
+    rest.split('"').next()
+}
+
+fn html_to_text(html: &str) -> String {
+    let text: String = tokenize(html)
+        .into_iter()
+        .filter(|(is_tag, _)| !is_tag)
+        .map(|(_, text)| text)
+        .collect();
+    decode_entities(text.trim())
+}
+
+/// Convert the puzzle description to markdown, similar to the files written by aoc-cli.
+fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut links: Vec<String> = vec![];
+    let mut in_pre = false;
+
+    for (is_tag, token) in tokenize(&extract_articles(html)) {
+        if !is_tag {
+            // line breaks between block elements of the source are not part of the text.
+            if in_pre || !token.trim().is_empty() || !token.contains('\n') {
+                markdown.push_str(&decode_entities(token));
+            }
+            continue;
+        }
+
+        match tag_name(token) {
+            "h2" => markdown.push_str("## "),
+            "/h2" | "/p" | "/ul" => markdown.push_str("\n\n"),
+            "pre" => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            "/pre" => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            "code" | "/code" if !in_pre => markdown.push('`'),
+            "em" | "/em" => markdown.push('*'),
+            "li" => markdown.push_str(" - "),
+            "/li" | "br" | "br/" => markdown.push('\n'),
+            "a" => {
+                links.push(attribute(token, "href").unwrap_or_default().to_string());
+                markdown.push('[');
+            }
+            "/a" => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    markdown.trim_end().to_string() + "\n"
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        path::PathBuf,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::{extract_articles, html_to_markdown, html_to_text, AocClient, AocClientError};
+    use crate::puzzle;
+
+    static PUZZLE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>The newly-improved <a href="/2023/about">calibration</a> document consists of lines of text.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>In this example, adding these together produces <code><em>142</em></code>.</p>
+<ul><li>one &amp; two</li><li><code>a &lt; b</code></li></ul>
+</article>
+<p>To begin, <a href="1/input" target="_blank">get your puzzle input</a>.</p>
+</main></body></html>"#;
+
+    /// Serves canned responses for every request and counts the requests it received.
+    fn mock_server(routes: Vec<(&'static str, u16, &'static str)>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(x) = header.to_lowercase().strip_prefix("content-length:") {
+                        content_length = x.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let path = request_line.split_whitespace().nth(1).unwrap();
+                let (status, response) = routes
+                    .iter()
+                    .find(|(route, _, _)| *route == path)
+                    .map_or((404, "not found"), |(_, status, body)| (*status, *body));
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (address, requests)
+    }
+
+    fn client(base_url: String, name: &str) -> AocClient {
+        let cache_dir =
+            std::env::temp_dir().join(format!("aoc-client-test-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        AocClient {
+            base_url,
+            session: "token".into(),
+            cache_dir,
+        }
+    }
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        let markdown = html_to_markdown(PUZZLE);
+        assert_eq!(
+            markdown,
+            [
+                "## --- Day 1: Trebuchet?! ---",
+                "",
+                "The newly-improved [calibration](/2023/about) document consists of lines of text.",
+                "",
+                "For example:",
+                "",
+                "```",
+                "1abc2",
+                "pqr3stu8vwx",
+                "```",
+                "",
+                "In this example, adding these together produces `*142*`.",
+                "",
+                " - one & two",
+                " - `a < b`",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn extracts_server_messages() {
+        let html = "<main><article><p>That's not the right answer; your answer is too <em>low</em>.</p></article></main>";
+        assert_eq!(
+            html_to_text(&extract_articles(html)),
+            "That's not the right answer; your answer is too low."
+        );
+    }
+
+    #[test]
+    fn downloads_and_caches_input() {
+        let (address, requests) = mock_server(vec![
+            ("/2023/day/1/input", 200, "1abc2\n"),
+            ("/2023/day/1", 200, PUZZLE),
+        ]);
+        let client = client(address, "download");
+        let dir = client.cache_dir.clone();
+        let input_path = dir.join("input.txt");
+        let puzzle_path = dir.join("puzzle.md");
+        let path = |p: &PathBuf| p.to_str().unwrap().to_string();
+
+        fs::create_dir_all(&dir).unwrap();
+        client
+            .download(puzzle!(2023, 1), &path(&input_path), &path(&puzzle_path))
+            .unwrap();
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "1abc2\n");
+        assert!(fs::read_to_string(&puzzle_path)
+            .unwrap()
+            .starts_with("## --- Day 1"));
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        // the input is served from the cache, the puzzle is refreshed.
+        client
+            .download(puzzle!(2023, 1), &path(&input_path), &path(&puzzle_path))
+            .unwrap();
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        // reading uses the cached puzzle.
+        assert!(client.read(puzzle!(2023, 1)).unwrap().contains("`*142*`"));
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (address, _) = mock_server(vec![(
+            "/2023/day/1/answer",
+            200,
+            "<article><p>That's the right answer! You are <span>one gold star</span> closer.</p></article>",
+        )]);
+        let client = client(address, "submit");
+        let message = client.submit(puzzle!(2023, 1), 1, "142").unwrap();
+        assert_eq!(
+            message,
+            "That's the right answer! You are one gold star closer."
+        );
+        let _ = fs::remove_dir_all(&client.cache_dir);
+    }
+
+    #[test]
+    fn reports_http_errors() {
+        let (address, _) = mock_server(vec![(
+            "/2023/day/1",
+            400,
+            "Please log in to get your puzzle input.",
+        )]);
+        let client = client(address, "error");
+        let result = client.read(puzzle!(2023, 1));
+        assert!(matches!(
+            result,
+            Err(AocClientError::Http(400, message)) if message == "Please log in to get your puzzle input."
+        ));
+        assert!(matches!(
+            client.read(puzzle!(2023, 2)),
+            Err(AocClientError::Http(404, _))
+        ));
+        let _ = fs::remove_dir_all(&client.cache_dir);
+    }
+}
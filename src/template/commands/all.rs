@@ -1,5 +1,23 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::{process, time::Duration};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{all_days, run_multi::run_multi, Year};
+
+pub fn handle(year: Year, is_release: bool, is_checked: bool, timeout: Option<Duration>) {
+    let summary = run_multi(
+        year,
+        &all_days().collect(),
+        is_release,
+        false,
+        is_checked,
+        timeout,
+    );
+
+    let has_failures = summary
+        .statuses
+        .iter()
+        .any(|(_, status)| status.is_failure());
+
+    if !summary.checks_passed || has_failures {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,93 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{examples::Example, puzzle_markdown::PuzzleExamples, PuzzleId};
+
+/// Write the examples of a downloaded puzzle description to `data/<year>/examples`,
+/// declaring their expected answers in the header of the example files.
+pub fn handle(puzzle: PuzzleId, overwrite: bool) {
+    let puzzle_path = puzzle.data_path("puzzles", "md");
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read puzzle description \"{puzzle_path}\", run `cargo download {} --year {}` first.",
+            puzzle.day, puzzle.year
+        );
+        process::exit(1);
+    };
+
+    let examples = PuzzleExamples::parse(&markdown);
+
+    // part two only gets its own example file if it differs from the one of part one.
+    let part_two_example = examples
+        .part_two
+        .input
+        .clone()
+        .filter(|input| examples.part_one.input.as_ref() != Some(input));
+
+    if let Some(input) = examples.part_one.input {
+        let example = Example {
+            part_one: examples.part_one.answer,
+            part_two: examples
+                .part_two
+                .answer
+                .clone()
+                .filter(|_| part_two_example.is_none()),
+            input,
+            ..Example::default()
+        };
+        write_example(&puzzle.data_path("examples", "txt"), example, overwrite);
+    }
+
+    if let Some(input) = part_two_example {
+        let example = Example {
+            part_one: None,
+            part_two: examples.part_two.answer,
+            input,
+            ..Example::default()
+        };
+        let path = format!("data/{}/examples/{}-2.txt", puzzle.year, puzzle.day);
+        write_example(&path, example, overwrite);
+    }
+}
+
+/// Example files are only replaced if they are empty, unless `overwrite` is set.
+/// If a file already contains the example, answers that are not declared yet are added to its header.
+fn write_example(path: &str, mut example: Example, overwrite: bool) {
+    let existing = fs::read_to_string(path)
+        .map(|content| Example::parse(&content))
+        .unwrap_or_default();
+
+    let is_empty = existing.input.trim().is_empty();
+
+    if !is_empty && !overwrite {
+        if existing.input.trim() != example.input.trim() {
+            println!(
+                "Example file \"{path}\" contains a different example, skipping. Use `--overwrite` to replace it."
+            );
+            return;
+        }
+        example.input = existing.input.clone();
+    }
+
+    // answers and parameters declared by hand are kept.
+    example.part_one = existing.part_one.clone().or(example.part_one);
+    example.part_two = existing.part_two.clone().or(example.part_two);
+    example.params.clone_from(&existing.params);
+
+    if example == existing {
+        println!("Example file \"{path}\" is up to date.");
+        return;
+    }
+
+    if let Some(parent) = Path::new(path).parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    match fs::write(path, example.to_string()) {
+        Ok(()) => println!("Wrote example to \"{path}\"."),
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,15 +1,18 @@
 use std::process;
 
-use crate::template::{aoc_cli, Day};
+use crate::template::{puzzle_client::PuzzleClient, PuzzleId};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(puzzle: PuzzleId) {
+    let client = match PuzzleClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to create puzzle client: {e}");
+            process::exit(1);
+        }
+    };
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = client.read(puzzle) {
+        eprintln!("failed to read puzzle: {e}");
         process::exit(1);
     };
 }
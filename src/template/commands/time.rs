@@ -1,29 +1,88 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process, time::Duration};
 
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::timings_compare;
+use crate::template::timings_history::{RunInfo, TimingsHistory};
+use crate::template::{all_days, readme_benchmarks, Day, PuzzleId, Year};
+
+/// Compare a fresh run against stored timings, see `cargo time --compare`.
+pub struct Compare {
+    /// Timings file to compare against, defaults to `data/timings.json`.
+    pub file: Option<String>,
+    /// Relative slowdown a part may have before it counts as regressed, e.g. `0.05` for 5%.
+    pub threshold: f64,
+}
+
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    history: bool,
+    compare: Option<Compare>,
+    timeout: Option<Duration>,
+) {
+    if history {
+        let Some(day) = day else {
+            eprintln!("The `--history` flag requires a day, e.g. `cargo time 01 --history`.");
+            process::exit(1);
+        };
+        read_history().print(PuzzleId::new(year, day));
+        return;
+    }
+
+    // read the history up front, so that a broken log is reported before benching rather than overwritten after.
+    let history = store.then(read_history);
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
 
+    let baseline = compare.as_ref().map(|compare| match &compare.file {
+        Some(file) => match Timings::read_from_path(file) {
+            Ok(timings) => (file.clone(), timings),
+            Err(e) => {
+                eprintln!("Could not read timings from \"{file}\": {e}");
+                process::exit(1);
+            }
+        },
+        None => ("data/timings.json".to_string(), stored_timings.clone()),
+    });
+
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
+            // a comparison needs fresh timings of every day.
+            if run_all || compare.is_some() {
                 all_days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
                 all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                    .filter(|day| !stored_timings.is_day_complete(PuzzleId::new(year, *day)))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    // registered days run in-process, so solution binaries are built with the same profile as this binary.
+    let is_release = !cfg!(debug_assertions);
+    let timings = run_multi(year, &days_to_run, is_release, true, false, timeout)
+        .timings
+        .unwrap();
+
+    let is_regressed = match (&baseline, &compare) {
+        (Some((source, baseline)), Some(compare)) => {
+            let comparisons = timings_compare::compare(baseline, &timings);
+            timings_compare::print(&comparisons, source, compare.threshold)
+        }
+        _ => false,
+    };
+
+    if let Some(mut history) = history {
+        history.append(&RunInfo::current(is_release), &timings);
+        if history.store_file().is_err() {
+            eprintln!("Failed to store benchmark history.");
+        }
 
-    if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
@@ -37,4 +96,15 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if is_regressed {
+        process::exit(1);
+    }
+}
+
+fn read_history() -> TimingsHistory {
+    TimingsHistory::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Could not read the benchmark history from \"data/timings_history.json\": {e}");
+        process::exit(1);
+    })
 }
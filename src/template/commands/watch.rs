@@ -0,0 +1,206 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{BufRead, BufReader},
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{runner::PartResult, PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Re-run a solution, or its tests if `test` is set, whenever its module, the library or its data files change.
+pub fn handle(puzzle: PuzzleId, release: bool, test: bool) {
+    let mut previous: HashMap<u8, Option<String>> = HashMap::new();
+    let mut last_seen = None;
+
+    loop {
+        let seen = snapshot(puzzle);
+
+        if last_seen.as_ref() == Some(&seen) {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+
+        last_seen = Some(seen);
+
+        // clear the screen and move the cursor to the top left.
+        print!("\x1b[2J\x1b[1;1H");
+        println!("{ANSI_BOLD}Watching {puzzle}{ANSI_RESET} {ANSI_ITALIC}(press Ctrl+C to stop){ANSI_RESET}");
+        println!("------");
+
+        if test {
+            run_tests(puzzle);
+        } else {
+            let results = run_solution(puzzle, release);
+            print_changes(&previous, &results);
+            for result in results {
+                previous.insert(result.part, result.answer);
+            }
+        }
+    }
+}
+
+/// Files that trigger a re-run: the solution module, the library modules and all data files of the day.
+fn watched_files(puzzle: PuzzleId) -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(format!("src/bin/{puzzle}.rs"))];
+
+    // helper modules such as `src/grid.rs` live next to `src/lib.rs`.
+    if let Ok(entries) = fs::read_dir("src") {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "rs") {
+                files.push(path);
+            }
+        }
+    }
+
+    for folder in ["inputs", "examples"] {
+        let dir = PathBuf::from(format!("data/{}/{folder}", puzzle.year));
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            if is_day_file(&entry.file_name().to_string_lossy(), puzzle) {
+                files.push(entry.path());
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// `true` for the data files of a day, e.g. `01.txt` and `01-2.txt` for day 1.
+fn is_day_file(name: &str, puzzle: PuzzleId) -> bool {
+    let day = puzzle.day.to_string();
+    name.strip_prefix(&day)
+        .is_some_and(|rest| rest == ".txt" || (rest.starts_with('-') && rest.ends_with(".txt")))
+}
+
+fn snapshot(puzzle: PuzzleId) -> Vec<(PathBuf, Option<SystemTime>)> {
+    watched_files(puzzle)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+fn run_solution(puzzle: PuzzleId, release: bool) -> Vec<PartResult> {
+    let mut args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
+
+    if release {
+        args.push("--release".to_string());
+    }
+
+    // request structured results to compare answers, the human-readable output moves to stderr.
+    args.extend(["--".to_string(), "--json".to_string()]);
+
+    let mut cmd = match Command::new("cargo")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+    {
+        Ok(cmd) => cmd,
+        Err(e) => {
+            eprintln!("Failed to run solution: {e}");
+            return vec![];
+        }
+    };
+
+    let mut results = vec![];
+
+    if let Some(stdout) = cmd.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            // anything that is not a result was printed by the solution itself.
+            match line.parse::<PartResult>() {
+                Ok(result) => results.push(result),
+                Err(_) => println!("{line}"),
+            }
+        }
+    }
+
+    let _ = cmd.wait();
+    results
+}
+
+fn run_tests(puzzle: PuzzleId) {
+    let status = Command::new("cargo")
+        .args(["test", "--bin", &puzzle.to_string()])
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status();
+
+    match status {
+        Ok(status) if status.success() => println!("{ANSI_BOLD}Tests passed.{ANSI_RESET}"),
+        Ok(_) => println!("{ANSI_BOLD}Tests failed.{ANSI_RESET}"),
+        Err(e) => eprintln!("Failed to run tests: {e}"),
+    }
+}
+
+/// Print the answer of every part next to the answer of the previous run.
+fn print_changes(previous: &HashMap<u8, Option<String>>, results: &[PartResult]) {
+    if previous.is_empty() || results.is_empty() {
+        return;
+    }
+
+    println!("------");
+    for result in results.iter().filter(|result| !result.is_parse()) {
+        let before = previous.get(&result.part).cloned().flatten();
+        println!(
+            "Part {}: {}",
+            result.part,
+            format_change(before.as_deref(), result.answer.as_deref())
+        );
+    }
+}
+
+fn format_change(before: Option<&str>, after: Option<&str>) -> String {
+    let after_str = after.unwrap_or("✖");
+    if before == after {
+        format!("{after_str} {ANSI_ITALIC}(unchanged){ANSI_RESET}")
+    } else {
+        format!(
+            "{after_str} {ANSI_ITALIC}(previously: {}){ANSI_RESET}",
+            before.unwrap_or("✖")
+        )
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_change, is_day_file};
+    use crate::puzzle;
+
+    #[test]
+    fn matches_data_files_of_the_day() {
+        let puzzle = puzzle!(2023, 1);
+        assert_eq!(is_day_file("01.txt", puzzle), true);
+        assert_eq!(is_day_file("01-2.txt", puzzle), true);
+        assert_eq!(is_day_file("11.txt", puzzle), false);
+        assert_eq!(is_day_file("01.md", puzzle), false);
+        assert_eq!(is_day_file("010.txt", puzzle), false);
+    }
+
+    #[test]
+    fn formats_changes() {
+        assert_eq!(
+            format_change(Some("1"), Some("1")).contains("unchanged"),
+            true
+        );
+        assert_eq!(
+            format_change(Some("1"), Some("2")).contains("2 \x1b[3m(previously: 1)"),
+            true
+        );
+        assert_eq!(
+            format_change(None, Some("2")).contains("previously: ✖"),
+            true
+        );
+    }
+}
@@ -0,0 +1,272 @@
+/// Example files declare the expected answers of each part in a small header:
+///
+/// ```text
+/// ---
+/// part_one: 405
+/// part_two: 400
+/// ---
+/// #.##..##.
+/// ```
+///
+/// Any other key of the header is a [puzzle parameter](crate::template::params), e.g. `steps: 6`.
+/// The `solution!` macro generates a test that runs every example file of the day against its declared answers.
+/// It also fails on parameters that no checked part reads, and on keys that look like a misspelled answer.
+use std::{env, fmt::Display, fs};
+
+use crate::template::{params::with_params, PuzzleId};
+
+const DELIMITER: &str = "---";
+
+/// An example input together with the answers and parameters declared in its header.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+    /// Parameters that differ from the real input, in the order they are declared.
+    pub params: Vec<(String, String)>,
+    pub input: String,
+}
+
+impl Example {
+    /// Parse the content of an example file, files without a header are returned as input only.
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        let mut example = Example::default();
+
+        let Some((header, input)) = split_header(content) else {
+            example.input = content.to_string();
+            return example;
+        };
+
+        for line in header.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+
+            let value = Some(value.trim())
+                .filter(|x| !x.is_empty())
+                .map(ToString::to_string);
+
+            match key.trim() {
+                "part_one" => example.part_one = value,
+                "part_two" => example.part_two = value,
+                key => {
+                    if let Some(value) = value {
+                        example.params.push((key.to_string(), value));
+                    }
+                }
+            }
+        }
+
+        example.input = input.to_string();
+        example
+    }
+
+    /// The expected answer of a part, if it is declared.
+    #[must_use]
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Example {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{DELIMITER}")?;
+        for (key, value) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+            match value {
+                Some(value) => writeln!(f, "{key}: {value}")?,
+                None => writeln!(f, "{key}:")?,
+            }
+        }
+        for (key, value) in &self.params {
+            writeln!(f, "{key}: {value}")?;
+        }
+        writeln!(f, "{DELIMITER}")?;
+        f.write_str(&self.input)
+    }
+}
+
+/// Split the content of an example file into its header and the input that follows it.
+fn split_header(content: &str) -> Option<(&str, &str)> {
+    let rest = content.strip_prefix(DELIMITER)?.strip_prefix('\n')?;
+
+    if let Some(header) = rest.strip_prefix(DELIMITER) {
+        // the header is empty.
+        return Some(("", header.strip_prefix('\n').unwrap_or(header)));
+    }
+
+    let end = rest.find(&format!("\n{DELIMITER}"))?;
+    let input = &rest[end + 1 + DELIMITER.len()..];
+    Some((&rest[..end], input.strip_prefix('\n').unwrap_or(input)))
+}
+
+/// Strip the header of an example file, returning the input only.
+#[must_use]
+pub fn strip_header(content: &str) -> String {
+    Example::parse(content).input
+}
+
+/// Names of the example files of a day in order, e.g. `01.txt`, `01-2.txt`, `01-3.txt`.
+#[must_use]
+pub fn example_files(puzzle: PuzzleId) -> Vec<String> {
+    let day = puzzle.day.to_string();
+    let dir = env::current_dir()
+        .unwrap_or_default()
+        .join("data")
+        .join(puzzle.year.to_string())
+        .join("examples");
+
+    let mut files: Vec<(u32, String)> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    let index = example_index(&name, &day)?;
+                    Some((index, name))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    files.sort();
+    files.into_iter().map(|(_, name)| name).collect()
+}
+
+/// `01.txt` is the first example of day 1, `01-2.txt` the second one and so on.
+fn example_index(name: &str, day: &str) -> Option<u32> {
+    let rest = name.strip_prefix(day)?.strip_suffix(".txt")?;
+    if rest.is_empty() {
+        return Some(1);
+    }
+    rest.strip_prefix('-')?.parse().ok()
+}
+
+/// A part of a solution, returning its answer as string or the error it failed with.
+pub type PartFn<'a> = &'a dyn Fn(&str) -> Result<Option<String>, String>;
+
+/// Run every example file of a day against the answers declared in its header, with the parameters of the example.
+///
+/// # Panics
+/// If any part does not return its declared answer.
+pub fn check(puzzle: PuzzleId, parts: &[(u8, PartFn)]) {
+    let mut failures = vec![];
+
+    for name in example_files(puzzle) {
+        let path = format!("data/{}/examples/{name}", puzzle.year);
+        let content = fs::read_to_string(&path).expect("could not open example file");
+        let example = Example::parse(&content);
+
+        // a misspelled answer would otherwise silently turn into a parameter and skip its part.
+        for (key, _) in &example.params {
+            if key.starts_with("part_") {
+                failures.push(format!(
+                    "{name}: unknown key {key}, expected part_one or part_two"
+                ));
+            }
+        }
+
+        // parameters that none of the checked parts read, `None` until a part is checked.
+        let mut unread: Option<Vec<String>> = None;
+
+        for (part, func) in parts {
+            let Some(expected) = example.answer(*part) else {
+                continue;
+            };
+
+            let (result, unread_by_part) = with_params(&example.params, || func(&example.input));
+            unread = Some(match unread {
+                Some(unread) => unread
+                    .into_iter()
+                    .filter(|key| unread_by_part.contains(key))
+                    .collect(),
+                None => unread_by_part,
+            });
+
+            match result {
+                Ok(Some(result)) if result == expected => {}
+                Ok(result) => failures.push(format!(
+                    "{name}, part {part}: expected {expected}, got {}",
+                    result.unwrap_or_else(|| "None".into())
+                )),
+                Err(e) => failures.push(format!(
+                    "{name}, part {part}: expected {expected}, got error: {e}"
+                )),
+            }
+        }
+
+        for key in unread.unwrap_or_default() {
+            if !key.starts_with("part_") {
+                failures.push(format!("{name}: parameter {key} is not read by any part"));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "examples of {puzzle} failed:\n{}",
+        failures.join("\n")
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{example_index, Example};
+
+    #[test]
+    fn parses_headers() {
+        let example = Example::parse("---\npart_one: 405\npart_two:\n---\n#.##\n..#.");
+        assert_eq!(example.part_one, Some("405".into()));
+        assert_eq!(example.part_two, None);
+        assert_eq!(example.input, "#.##\n..#.");
+        assert_eq!(example.answer(1), Some("405"));
+        assert_eq!(example.params, vec![]);
+    }
+
+    #[test]
+    fn parses_params() {
+        let example = Example::parse("---\npart_one: 16\nsteps: 6\nmin:\n---\n...");
+        assert_eq!(example.part_one, Some("16".into()));
+        assert_eq!(example.params, vec![("steps".into(), "6".into())]);
+        assert_eq!(example.input, "...");
+    }
+
+    #[test]
+    fn parses_files_without_header() {
+        let example = Example::parse("#.##\n..#.\n");
+        assert_eq!(example.part_one, None);
+        assert_eq!(example.input, "#.##\n..#.\n");
+        assert_eq!(Example::parse("---\n---\nabc").input, "abc");
+        assert_eq!(Example::parse("").input, "");
+    }
+
+    #[test]
+    fn roundtrips_examples() {
+        let example = Example {
+            part_one: None,
+            part_two: Some("abc".into()),
+            params: vec![("steps".into(), "6".into())],
+            input: "1\n2\n".into(),
+        };
+        let content = example.to_string();
+        assert_eq!(
+            content,
+            "---\npart_one:\npart_two: abc\nsteps: 6\n---\n1\n2\n"
+        );
+        assert_eq!(Example::parse(&content), example);
+    }
+
+    #[test]
+    fn orders_example_files() {
+        assert_eq!(example_index("01.txt", "01"), Some(1));
+        assert_eq!(example_index("01-3.txt", "01"), Some(3));
+        assert_eq!(example_index("11.txt", "01"), None);
+        assert_eq!(example_index("01-a.txt", "01"), None);
+    }
+}
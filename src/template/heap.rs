@@ -0,0 +1,153 @@
+/// Lightweight heap metrics for solution parts.
+///
+/// With the `count-alloc` feature, solutions install [`CountingAlloc`] as their global allocator.
+/// It forwards to the system allocator and counts allocations, allocated bytes and live bytes.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// `dhat` installs its own global allocator, so counting is only possible without it.
+pub const IS_ENABLED: bool = cfg!(all(feature = "count-alloc", not(feature = "dhat-heap")));
+
+#[cfg(all(test, feature = "count-alloc", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Heap usage of a single invocation of a solution part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Sum of the bytes requested by all allocations.
+    pub allocated_bytes: u64,
+    /// Highest amount of bytes that were live at the same time, on top of what was live before the part ran.
+    pub peak_bytes: u64,
+}
+
+/// Global allocator that counts heap usage before forwarding to [`System`].
+pub struct CountingAlloc;
+
+impl CountingAlloc {
+    fn record_alloc(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE_BYTES.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            LIVE_BYTES.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Run `func` and record its heap usage. Returns `None` for the stats if counting is not enabled.
+///
+/// Allocations of all threads are counted, so parts should be measured one at a time.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<HeapStats>) {
+    if !IS_ENABLED {
+        return (func(), None);
+    }
+
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    ALLOCATED_BYTES.store(0, Ordering::Relaxed);
+    let baseline = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(baseline, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = HeapStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK_LIVE_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(baseline),
+    };
+
+    (result, Some(stats))
+}
+
+/// Format a byte count with binary units, e.g. `1.5 KiB`.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, measure, IS_ENABLED};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn skips_stats_without_counting_allocator() {
+        let (result, stats) = measure(|| vec![1_u8; 64].len());
+        assert_eq!(result, 64);
+        assert_eq!(stats.is_some(), IS_ENABLED);
+    }
+
+    #[test]
+    #[cfg(all(feature = "count-alloc", not(feature = "dhat-heap")))]
+    fn counts_allocations() {
+        let (_, stats) = measure(|| vec![1_u8; 64]);
+        let stats = stats.unwrap();
+        assert!(stats.allocations >= 1);
+        assert!(stats.allocated_bytes >= 64);
+    }
+}
@@ -1,68 +1,184 @@
-use std::{env, fs};
+use std::{env, fs, io};
 
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod examples;
+pub mod heap;
+pub mod params;
+pub mod puzzle_client;
+pub mod registry;
 pub mod runner;
+pub mod variants;
 
 pub use day::*;
+pub use puzzle_id::*;
+pub use year::*;
 
+mod answers;
 mod day;
+mod puzzle_id;
+mod puzzle_markdown;
 mod readme_benchmarks;
 mod run_multi;
+mod submissions;
 mod timings;
+mod timings_compare;
+mod timings_history;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a puzzle to a string, e.g. `data/2023/inputs/01.txt`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    let f = try_read_file(folder, puzzle);
     f.expect("could not open input file")
 }
 
+/// Helper function that reads a text file to a string, returning an error if it can't be read.
+/// The header of example files is stripped.
+pub fn try_read_file(folder: &str, puzzle: PuzzleId) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let content = fs::read_to_string(cwd.join(puzzle.data_path(folder, "txt")))?;
+    Ok(strip_example_header(folder, content))
+}
+
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(puzzle.year.to_string())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    strip_example_header(folder, f.expect("could not open input file"))
+}
+
+fn strip_example_header(folder: &str, content: String) -> String {
+    if folder == "examples" {
+        examples::strip_header(&content)
+    } else {
+        content
+    }
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constant `PUZZLE` and sets up the input and runner for each part.
+///
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// An optional parameter `parse = <fn>` sets a parse function that runs once, the parts then borrow its output.
+/// Its timing is reported separately from the parts, e.g. `solution!(2023, 1, parse = parse_input)`.
+///
+/// An optional, last parameter `variants = [<part>: <fn>, ...]` registers alternative implementations of a part,
+/// which are compared against the part when run with `--variants`, e.g. `solution!(2023, 1, variants = [1: part_one_naive])`.
+///
+/// In test builds, it also creates the test `test_examples` that checks the answers declared in the day's example files.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr, 1 $(, parse = $parse:expr)? $(, variants = [$($vpart:literal : $vfunc:ident),* $(,)?])?) => {
+        $crate::solution!(@impl $year, $day, [$($parse)?], [$($($vpart: $vfunc),*)?], [part_one, 1]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, 2 $(, parse = $parse:expr)? $(, variants = [$($vpart:literal : $vfunc:ident),* $(,)?])?) => {
+        $crate::solution!(@impl $year, $day, [$($parse)?], [$($($vpart: $vfunc),*)?], [part_two, 2]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr $(, parse = $parse:expr)? $(, variants = [$($vpart:literal : $vfunc:ident),* $(,)?])?) => {
+        $crate::solution!(@impl $year, $day, [$($parse)?], [$($($vpart: $vfunc),*)?], [part_one, 1] [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    // parses an example for `test_examples`, without a parse function the parts borrow the input as is.
+    (@parser) => {
+        |input: &str| input.to_string()
+    };
+    (@parser $parse:expr) => {
+        $parse
+    };
+
+    (@impl $year:expr, $day:expr, [$($parse:expr)?], [$($vpart:literal : $vfunc:ident),*], $( [$func:expr, $part:expr] )*) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId = $crate::puzzle!($year, $day);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        #[cfg(all(feature = "count-alloc", not(feature = "dhat-heap")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::heap::CountingAlloc = $crate::template::heap::CountingAlloc;
+
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            use $crate::template::variants::{self, Variant};
+            let input = $crate::template::read_file("inputs", PUZZLE);
+            $( let input = run_parse($parse, &input); )?
+
+            if variants::is_enabled() {
+                variants::run(&[
+                    $( Variant { part: $part, name: stringify!($func), func: &|| PartOutput::to_answer(&$func(&input)) }, )*
+                    $( Variant { part: $vpart, name: stringify!($vfunc), func: &|| PartOutput::to_answer(&$vfunc(&input)) }, )*
+                ]);
+                return;
+            }
+
+            let results = [$( run_part($func, &input, PUZZLE, $part), )*];
+            check_results(PUZZLE, &results);
+        }
+
+        /// Runs every example file of the day against the answers declared in its header.
+        /// Variants are checked against the answers of their part.
+        #[cfg(test)]
+        #[test]
+        fn test_examples() {
+            let parse = $crate::solution!(@parser $($parse)?);
+            $crate::template::examples::check(
+                PUZZLE,
+                &[
+                    $( ($part, &|input: &str| $crate::template::runner::PartOutput::to_answer(&$func(&parse(input)))), )*
+                    $( ($vpart, &|input: &str| $crate::template::runner::PartOutput::to_answer(&$vfunc(&parse(input)))), )*
+                ],
+            );
         }
     };
 }
+
+/// Variant of [`solution!`] used when a day is linked into the library by the solution registry.
+///
+/// Instead of a `main` function, it creates the constant `SOLUTION` that the registry collects.
+/// Variants of a part are not registered, they only run as part of `cargo solve --variants`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __registered_solution {
+    ($year:expr, $day:expr, 1 $(, parse = $parse:expr)? $(, variants = [$($variants:tt)*])?) => {
+        $crate::__registered_solution!(@impl $year, $day, [$($parse)?], [part_one, 1]);
+    };
+    ($year:expr, $day:expr, 2 $(, parse = $parse:expr)? $(, variants = [$($variants:tt)*])?) => {
+        $crate::__registered_solution!(@impl $year, $day, [$($parse)?], [part_two, 2]);
+    };
+    ($year:expr, $day:expr $(, parse = $parse:expr)? $(, variants = [$($variants:tt)*])?) => {
+        $crate::__registered_solution!(@impl $year, $day, [$($parse)?], [part_one, 1] [part_two, 2]);
+    };
+
+    (@impl $year:expr, $day:expr, [$($parse:expr)?], $( [$func:expr, $part:expr] )*) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId = $crate::puzzle!($year, $day);
+
+        pub const SOLUTION: $crate::template::registry::Solution = $crate::template::registry::Solution {
+            puzzle: PUZZLE,
+            run: |input, is_timed| {
+                use $crate::template::runner::*;
+                let mut results = vec![];
+                $(
+                    let (parsed, result) = solve_parse($parse, input, is_timed);
+                    results.push(result);
+                    let input = &parsed;
+                )?
+                results.extend([$( solve_part($func, input, $part, is_timed), )*]);
+                results
+            },
+        };
+    };
+}
@@ -0,0 +1,184 @@
+/// Named puzzle parameters, for constants that differ between the examples and the real input.
+///
+/// A part reads a parameter with its value for the real input as default, e.g. `param("steps", 64)?`.
+/// Example files override it in their header, next to the expected answers:
+///
+/// ```text
+/// ---
+/// part_one: 16
+/// part_two:
+/// steps: 6
+/// ---
+/// ...........
+/// ```
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::Display,
+    str::FromStr,
+    sync::{Mutex, PoisonError},
+};
+
+/// The parameters of the input a thread runs a part on, and whether the part has read them.
+struct Scope {
+    overrides: Vec<(String, String)>,
+    read: Vec<bool>,
+}
+
+impl Scope {
+    fn unread(&self) -> Vec<String> {
+        self.overrides
+            .iter()
+            .zip(&self.read)
+            .filter(|(_, read)| !**read)
+            .map(|((key, _), _)| key.clone())
+            .collect()
+    }
+}
+
+thread_local! {
+    static SCOPE: RefCell<Option<Scope>> = const { RefCell::new(None) };
+}
+
+/// Number of threads that currently override each parameter.
+static OVERRIDDEN: Mutex<BTreeMap<String, usize>> = Mutex::new(BTreeMap::new());
+
+fn update_overridden(params: &[(String, String)], delta: isize) {
+    let mut counts = OVERRIDDEN.lock().unwrap_or_else(PoisonError::into_inner);
+    for (key, _) in params {
+        let count = counts.entry(key.clone()).or_default();
+        *count = count.saturating_add_signed(delta);
+    }
+    counts.retain(|_, count| *count > 0);
+}
+
+fn is_overridden_elsewhere(name: &str) -> bool {
+    OVERRIDDEN
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .contains_key(name)
+}
+
+/// Value of the parameter `name` for the current input, `default` if the input does not override it.
+///
+/// Overrides only apply to the thread that runs the part, read parameters before handing work off to other threads.
+///
+/// # Errors
+/// If the override can not be parsed as `T`, or if `name` is overridden on another thread while this one does not
+/// run a part, e.g. because it is a worker the part handed work off to.
+pub fn param<T: FromStr>(name: &str, default: T) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    let value = SCOPE.with(|scope| {
+        let mut scope = scope.borrow_mut();
+        let scope = scope.as_mut()?;
+        let index = scope.overrides.iter().position(|(key, _)| key == name);
+        Some(index.map(|index| {
+            scope.read[index] = true;
+            scope.overrides[index].1.clone()
+        }))
+    });
+
+    match value {
+        Some(Some(value)) => value
+            .parse()
+            .map_err(|e| format!("invalid value \"{value}\" for parameter {name}: {e}")),
+        Some(None) => Ok(default),
+        None if is_overridden_elsewhere(name) => Err(format!(
+            "parameter {name} is read outside of the thread that runs the part, read it before handing work off"
+        )),
+        None => Ok(default),
+    }
+}
+
+/// Run `func` with the parameters of an input, restoring the previous ones afterwards.
+///
+/// Returns the result of `func` and the names of the parameters it did not read.
+pub fn with_params<R>(params: &[(String, String)], func: impl FnOnce() -> R) -> (R, Vec<String>) {
+    let scope = Scope {
+        overrides: params.to_vec(),
+        read: vec![false; params.len()],
+    };
+    let previous = SCOPE.with(|current| current.replace(Some(scope)));
+    update_overridden(params, 1);
+
+    // restore the previous parameters even if the part panics.
+    struct Restore<'a> {
+        params: &'a [(String, String)],
+        previous: Option<Scope>,
+    }
+
+    impl Drop for Restore<'_> {
+        fn drop(&mut self) {
+            update_overridden(self.params, -1);
+            SCOPE.with(|scope| scope.replace(self.previous.take()));
+        }
+    }
+
+    let _restore = Restore { params, previous };
+    let result = func();
+    let unread = SCOPE.with(|scope| scope.borrow().as_ref().map(Scope::unread));
+
+    (result, unread.unwrap_or_default())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{param, with_params};
+    use std::thread;
+
+    fn params(values: &[(&str, &str)]) -> Vec<(String, String)> {
+        values
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    // tests run in parallel and overrides are visible to other threads, so every test uses its own names.
+
+    #[test]
+    fn uses_defaults_without_overrides() {
+        assert_eq!(param("rounds", 64), Ok(64));
+        let (value, unread) = with_params(&params(&[("min", "7")]), || param("rounds", 64));
+        assert_eq!(value, Ok(64));
+        assert_eq!(unread, vec!["min".to_string()]);
+    }
+
+    #[test]
+    fn applies_overrides() {
+        let overrides = params(&[("steps", "6"), ("factor", "10")]);
+        let (value, unread) = with_params(&overrides, || param("steps", 64u64));
+        assert_eq!(value, Ok(6));
+        assert_eq!(unread, vec!["factor".to_string()]);
+
+        let (value, unread) =
+            with_params(&overrides, || (param("steps", 64u64), param("factor", 1.5)));
+        assert_eq!(value, (Ok(6), Ok(10.0)));
+        assert_eq!(unread, Vec::<String>::new());
+    }
+
+    #[test]
+    fn rejects_invalid_overrides() {
+        let (value, _) = with_params(&params(&[("size", "abc")]), || param("size", 64));
+        assert_eq!(
+            value,
+            Err("invalid value \"abc\" for parameter size: invalid digit found in string".into())
+        );
+    }
+
+    #[test]
+    fn rejects_reads_from_other_threads() {
+        let (value, unread) = with_params(&params(&[("workers", "2")]), || {
+            thread::spawn(|| param("workers", 1)).join().unwrap()
+        });
+        assert!(value
+            .unwrap_err()
+            .contains("outside of the thread that runs the part"));
+        assert_eq!(unread, vec!["workers".to_string()]);
+        // once the part is done, other threads read the default again.
+        assert_eq!(thread::spawn(|| param("workers", 1)).join().unwrap(), Ok(1));
+    }
+}
@@ -0,0 +1,115 @@
+/// Common interface for the backends that talk to the advent of code website.
+/// The backend is selected with the `AOC_CLIENT` environment variable: `native` (default) or `aoc-cli`.
+use std::{env, fmt::Display, fs, path::Path};
+
+use crate::template::{
+    aoc_cli::{self, AocCommandError},
+    aoc_client::{AocClient, AocClientError},
+    PuzzleId,
+};
+
+pub enum PuzzleClient {
+    Native(AocClient),
+    AocCli,
+}
+
+#[derive(Debug)]
+pub enum PuzzleClientError {
+    Native(AocClientError),
+    AocCli(AocCommandError),
+    UnknownBackend(String),
+}
+
+impl Display for PuzzleClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleClientError::Native(e) => write!(f, "{e}"),
+            PuzzleClientError::AocCli(e) => write!(f, "{e}"),
+            PuzzleClientError::UnknownBackend(x) => write!(
+                f,
+                "unknown client `{x}` in `AOC_CLIENT`, expected `native` or `aoc-cli`."
+            ),
+        }
+    }
+}
+
+impl From<AocClientError> for PuzzleClientError {
+    fn from(e: AocClientError) -> Self {
+        PuzzleClientError::Native(e)
+    }
+}
+
+impl From<AocCommandError> for PuzzleClientError {
+    fn from(e: AocCommandError) -> Self {
+        PuzzleClientError::AocCli(e)
+    }
+}
+
+impl PuzzleClient {
+    pub fn from_env() -> Result<Self, PuzzleClientError> {
+        match env::var("AOC_CLIENT").as_deref() {
+            Ok("native") | Err(_) => Ok(PuzzleClient::Native(AocClient::from_env()?)),
+            Ok("aoc-cli") => {
+                aoc_cli::check()?;
+                Ok(PuzzleClient::AocCli)
+            }
+            Ok(x) => Err(PuzzleClientError::UnknownBackend(x.into())),
+        }
+    }
+
+    /// Download the input and description of a puzzle to `data/<year>/inputs` and `data/<year>/puzzles`.
+    pub fn download(&self, puzzle: PuzzleId) -> Result<(), PuzzleClientError> {
+        let input_path = puzzle.data_path("inputs", "txt");
+        let puzzle_path = puzzle.data_path("puzzles", "md");
+
+        for path in [&input_path, &puzzle_path] {
+            if let Some(dir) = Path::new(path).parent() {
+                fs::create_dir_all(dir).map_err(AocClientError::from)?;
+            }
+        }
+
+        match self {
+            PuzzleClient::Native(client) => client.download(puzzle, &input_path, &puzzle_path)?,
+            PuzzleClient::AocCli => {
+                aoc_cli::download(puzzle, &input_path, &puzzle_path)?;
+            }
+        }
+
+        println!("---");
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(())
+    }
+
+    /// Print the description of a puzzle.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<(), PuzzleClientError> {
+        match self {
+            PuzzleClient::Native(client) => println!("{}", client.read(puzzle)?),
+            PuzzleClient::AocCli => {
+                aoc_cli::read(puzzle, &puzzle.data_path("puzzles", "md"))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Submit an answer and return the message of the server.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, PuzzleClientError> {
+        let message = match self {
+            PuzzleClient::Native(client) => client.submit(puzzle, part, answer)?,
+            PuzzleClient::AocCli => {
+                let output = match aoc_cli::submit(puzzle, part, answer) {
+                    Ok(output) | Err(AocCommandError::BadExitStatus(output)) => output,
+                    Err(e) => return Err(e.into()),
+                };
+                String::from_utf8_lossy(&output.stdout).to_string()
+            }
+        };
+        println!("{}", message.trim_end());
+        Ok(message)
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+/// Identifies a puzzle by its year and day.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of the solution's binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2023).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Path to a data file of the puzzle, e.g. `data/2023/inputs/08.txt`.
+    pub fn data_path(self, folder: &str, extension: &str) -> String {
+        format!("data/{}/{folder}/{}.{extension}", self.year, self.day)
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle of the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        Some(Self::new(Year::today()?, Day::today()?))
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('-').ok_or(PuzzleIdFromStrError)?;
+        Ok(Self {
+            year: year.parse().map_err(|_| PuzzleIdFromStrError)?,
+            day: day.parse().map_err(|_| PuzzleIdFromStrError)?,
+        })
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a puzzle in the format `<year>-<day>`, e.g. `2023-01`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Store a puzzle as the `year` and `day` keys of a JSON object.
+pub(crate) fn insert_puzzle_json(map: &mut HashMap<String, JsonValue>, puzzle: PuzzleId) {
+    map.insert("year".into(), JsonValue::String(puzzle.year.to_string()));
+    map.insert("day".into(), JsonValue::String(puzzle.day.to_string()));
+}
+
+/// Read a puzzle from the `year` and `day` keys of a JSON object.
+/// Files written before multi-year support do not have a `year`, it then defaults to `AOC_YEAR`.
+pub(crate) fn puzzle_from_json(
+    json: &HashMap<String, JsonValue>,
+    name: &str,
+) -> Result<PuzzleId, String> {
+    let day = json
+        .get("day")
+        .and_then(|v| v.get::<String>())
+        .and_then(|day| Day::from_str(day).ok())
+        .ok_or(format!("Expected {name}.day to be a Day struct."))?;
+
+    let year = match json.get("year") {
+        None => Year::from_env(),
+        Some(v) => v.get::<String>().and_then(|year| Year::from_str(year).ok()),
+    }
+    .ok_or(format!("Expected {name}.year to be a Year struct."))?;
+
+    Ok(PuzzleId::new(year, day))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`PuzzleId`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {
+        $crate::template::PuzzleId::new($crate::year!($year), $crate::day!($day))
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::PuzzleId;
+    use crate::puzzle;
+
+    #[test]
+    fn roundtrips_puzzle_ids() {
+        let puzzle = puzzle!(2022, 8);
+        assert_eq!(puzzle.to_string(), "2022-08");
+        assert_eq!("2022-08".parse::<PuzzleId>().unwrap(), puzzle);
+        assert_eq!("2022-8".parse::<PuzzleId>().unwrap(), puzzle);
+        assert_eq!("08".parse::<PuzzleId>().is_err(), true);
+        assert_eq!("2022-26".parse::<PuzzleId>().is_err(), true);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut map = std::collections::HashMap::new();
+        super::insert_puzzle_json(&mut map, puzzle!(2022, 8));
+        assert_eq!(
+            super::puzzle_from_json(&map, "timing").unwrap(),
+            puzzle!(2022, 8)
+        );
+        map.remove("day");
+        assert_eq!(super::puzzle_from_json(&map, "timing").is_err(), true);
+    }
+
+    #[test]
+    fn builds_data_paths() {
+        assert_eq!(
+            puzzle!(2023, 1).data_path("inputs", "txt"),
+            "data/2023/inputs/01.txt"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+/// Module that extracts the examples and their expected answers from a puzzle description.
+/// Descriptions are read from the markdown written by `download`, which renders the puzzle's
+/// `<pre>` blocks as fenced code blocks and highlighted answers as `` `*142*` ``.
+use std::mem;
+
+/// Example input and expected answer of a puzzle part.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PartExample {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Examples of both parts. `part_two` is empty until the first part has been solved.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    pub part_one: PartExample,
+    pub part_two: PartExample,
+}
+
+impl PuzzleExamples {
+    pub fn parse(markdown: &str) -> Self {
+        let (one, two) = split_parts(markdown);
+        PuzzleExamples {
+            part_one: parse_part(one),
+            part_two: two.map(parse_part).unwrap_or_default(),
+        }
+    }
+}
+
+/// Split the description at the `--- Part Two ---` heading.
+fn split_parts(markdown: &str) -> (&str, Option<&str>) {
+    match markdown.find("Part Two ---") {
+        Some(pos) => {
+            // move back to the start of the heading's line.
+            let start = markdown[..pos].rfind('\n').map_or(0, |x| x + 1);
+            (&markdown[..start], Some(&markdown[start..]))
+        }
+        None => (markdown, None),
+    }
+}
+
+fn parse_part(section: &str) -> PartExample {
+    PartExample {
+        input: find_example(section),
+        answer: find_answers(section).pop(),
+    }
+}
+
+/// The example is the first code block that is introduced by a paragraph mentioning an example,
+/// falling back to the first code block of the section.
+fn find_example(section: &str) -> Option<String> {
+    let blocks = code_blocks(section);
+
+    blocks
+        .iter()
+        .find(|(intro, _)| intro.to_lowercase().contains("example"))
+        .or(blocks.first())
+        .map(|(_, block)| block.clone())
+}
+
+/// Fenced code blocks of a section, together with the text that precedes each of them.
+fn code_blocks(section: &str) -> Vec<(String, String)> {
+    let mut blocks = vec![];
+    let mut text = String::new();
+    let mut block: Option<String> = None;
+
+    for line in section.lines() {
+        if line.trim_start().starts_with("```") {
+            match block.take() {
+                Some(content) => {
+                    blocks.push((mem::take(&mut text), content));
+                }
+                None => block = Some(String::new()),
+            }
+            continue;
+        }
+
+        match block.as_mut() {
+            Some(content) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+            None if !line.trim().is_empty() => {
+                // only the paragraph right before a block introduces it.
+                text = line.to_string();
+            }
+            None => {}
+        }
+    }
+
+    blocks
+}
+
+/// Highlighted values of a section, i.e. code spans with emphasis: `` `*142*` `` or `` *`142`* ``.
+fn find_answers(section: &str) -> Vec<String> {
+    let mut answers = vec![];
+    let mut rest = section;
+
+    // scan from left to right, so that the closing marker of a span is never taken for an opening one.
+    while let Some(start) = rest.find(['`', '*']) {
+        let opening = rest.get(start..start + 2);
+        let closing = match opening {
+            Some("`*") => "*`",
+            Some("*`") => "`*",
+            _ => {
+                rest = &rest[start + 1..];
+                continue;
+            }
+        };
+
+        let after = &rest[start + 2..];
+        let Some(end) = after.find(closing) else {
+            break;
+        };
+
+        let value = &after[..end];
+        if !value.is_empty() && !value.contains(['`', '*', '\n']) {
+            answers.push(value.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+
+    answers
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartExample, PuzzleExamples};
+
+    static PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+The newly-improved calibration document consists of lines of text.
+
+Consider this grid:
+
+```
+###
+```
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values are `*12*` and `*38*`. Adding these together produces `*142*`.
+
+## --- Part Two ---
+
+Your calculation isn't quite right.
+
+For example:
+
+```
+two1nine
+```
+
+Adding these together produces *`281`*.
+";
+
+    #[test]
+    fn parses_examples_of_both_parts() {
+        let examples = PuzzleExamples::parse(PUZZLE);
+        assert_eq!(
+            examples.part_one,
+            PartExample {
+                input: Some("1abc2\npqr3stu8vwx\n".into()),
+                answer: Some("142".into()),
+            }
+        );
+        assert_eq!(
+            examples.part_two,
+            PartExample {
+                input: Some("two1nine\n".into()),
+                answer: Some("281".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_first_part_only() {
+        let markdown = PUZZLE.split("## --- Part Two").next().unwrap();
+        let examples = PuzzleExamples::parse(markdown);
+        assert_eq!(examples.part_one.answer, Some("142".into()));
+        assert_eq!(examples.part_two, PartExample::default());
+    }
+}
@@ -2,8 +2,9 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::heap::{format_bytes, HeapStats};
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::PuzzleId;
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -26,8 +27,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -52,35 +53,106 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
+fn format_heap(heap: Option<HeapStats>) -> String {
+    match heap {
+        Some(heap) => format!(
+            "`{}` ({} allocs)",
+            format_bytes(heap.peak_bytes),
+            heap.allocations
+        ),
+        None => "-".into(),
+    }
+}
+
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // heap columns are only shown if timings were recorded with the `count-alloc` feature.
+    let has_heap = timings
+        .data
+        .iter()
+        .any(|t| t.part_1_heap.is_some() || t.part_2_heap.is_some());
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    // the parse column is only shown if a solution times its parse step separately.
+    let has_parse = timings.data.iter().any(|t| t.parse.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    let years = timings.years();
+
+    if years.len() > 1 {
+        // one table per year, followed by the total of all years.
+        for year in years {
+            let timings = timings.for_year(year);
+            let year_millis = timings.total_millis();
+            lines.push(format!("{prefix}# {year}"));
+            lines.push(String::new());
+            lines.extend(construct_rows(timings, has_parse, has_heap));
+            lines.push(String::new());
+            lines.push(format!("**Total ({year}): {year_millis:.2}ms**"));
+            lines.push(String::new());
+        }
+    } else {
+        lines.extend(construct_rows(timings, has_parse, has_heap));
+        lines.push(String::new());
     }
 
-    lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
+fn construct_rows(timings: Timings, has_parse: bool, has_heap: bool) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+
+    let (parse_header, parse_align) = if has_parse {
+        (" Parse |", " :---: |")
+    } else {
+        ("", "")
+    };
+
+    if has_heap {
+        lines.push(format!(
+            "| Day |{parse_header} Part 1 | Part 2 | Part 1 peak heap | Part 2 peak heap |"
+        ));
+        lines.push(format!(
+            "| :---: |{parse_align} :---: | :---:  | :---: | :---: |"
+        ));
+    } else {
+        lines.push(format!("| Day |{parse_header} Part 1 | Part 2 |"));
+        lines.push(format!("| :---: |{parse_align} :---: | :---:  |"));
+    }
+
+    for timing in timings.data {
+        let path = get_path_for_bin(timing.puzzle);
+        let parse = if has_parse {
+            format!(" `{}` |", timing.parse.unwrap_or_else(|| "-".into()))
+        } else {
+            String::new()
+        };
+        let mut line = format!(
+            "| [Day {}]({}) |{parse} `{}` | `{}` |",
+            timing.puzzle.day.into_inner(),
+            path,
+            timing.part_1.unwrap_or_else(|| "-".into()),
+            timing.part_2.unwrap_or_else(|| "-".into())
+        );
+
+        if has_heap {
+            line.push_str(&format!(
+                " {} | {} |",
+                format_heap(timing.part_1_heap),
+                format_heap(timing.part_2_heap)
+            ));
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
@@ -100,27 +172,47 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        puzzle, template::heap::HeapStats, template::timings::Timing, template::timings::Timings,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2023, 2),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: puzzle!(2023, 4),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 9e+10,
                 },
             ],
@@ -169,9 +261,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +272,82 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_heap() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_heap = Some(HeapStats {
+            allocations: 12,
+            allocated_bytes: 4096,
+            peak_bytes: 1536,
+        });
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert_eq!(
+            s.contains("| Day | Part 1 | Part 2 | Part 1 peak heap | Part 2 peak heap |"),
+            true
+        );
+        assert_eq!(
+            s.contains(
+                "| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` | `1.5 KiB` (12 allocs) | - |"
+            ),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` | - | - |"),
+            true
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_with_parse() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some("5ms".into());
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert_eq!(s.contains("| Day | Parse | Part 1 | Part 2 |"), true);
+        assert_eq!(s.contains("| :---: | :---: | :---: | :---:  |"), true);
+        assert_eq!(
+            s.contains("| [Day 1](./src/bin/2023-01.rs) | `-` | `10ms` | `20ms` |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/2023-02.rs) | `5ms` | `30ms` | `40ms` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_of_several_years() {
+        let mut timings = get_mock_timings();
+        timings.data[0].puzzle = puzzle!(2022, 1);
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "### 2022",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2022-01.rs) | `10ms` | `20ms` |",
+            "",
+            "**Total (2022): 30000.00ms**",
+            "",
+            "### 2023",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `40ms` | `50ms` |",
+            "",
+            "**Total (2023): 160000.00ms**",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
 }
@@ -0,0 +1,41 @@
+/// Links every scaffolded day into the library, so that `all` and `time` can run solutions in-process.
+/// The list of days is generated by `build.rs` from the modules found in `src/bin`.
+use crate::template::{runner::PartResult, PuzzleId};
+
+/// Runs all parts of a day against an input, benching them if the second argument is `true`.
+/// If the day has a parse function, its timing is the first result.
+pub type SolutionRunner = fn(&str, bool) -> Vec<PartResult>;
+
+/// A day's solution as registered by the `solution!` macro.
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub run: SolutionRunner,
+}
+
+/// Stands in for the `advent_of_code` crate inside of the linked solution modules.
+/// Everything resolves to the library, except for `solution!` which registers the day instead of creating a `main` function.
+#[doc(hidden)]
+pub mod shim {
+    pub use crate::*;
+
+    pub use crate::__registered_solution as solution;
+}
+
+// NOTE: days are not linked into the library's own test build, their tests already run as part of the binaries.
+#[cfg(not(test))]
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+#[cfg(test)]
+static SOLUTIONS: &[Solution] = &[];
+
+/// All solutions that are linked into the library, ordered by year and day.
+#[must_use]
+pub fn solutions() -> &'static [Solution] {
+    SOLUTIONS
+}
+
+/// Look up the registered solution for a puzzle.
+#[must_use]
+pub fn find(puzzle: PuzzleId) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|solution| solution.puzzle == puzzle)
+}
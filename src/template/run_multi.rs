@@ -1,14 +1,93 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    io, process,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    registry,
+    runner::{PartResult, PartStatus},
+    try_read_file, Day, PuzzleId, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
 use super::{
     all_days,
+    answers::{Answers, CheckOutcome},
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Outcome of running a single day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DayStatus {
+    Ok,
+    /// At least one part did not match its accepted answer in `data/answers.json`.
+    WrongAnswer,
+    /// At least one part returned an error.
+    Errored,
+    /// No part returned a result.
+    Unsolved,
+    Panicked,
+    TimedOut,
+    NoInput,
+    NotScaffolded,
+}
+
+impl DayStatus {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DayStatus::Ok => "ok",
+            DayStatus::WrongAnswer => "wrong answer",
+            DayStatus::Errored => "error",
+            DayStatus::Unsolved => "unsolved",
+            DayStatus::Panicked => "panicked",
+            DayStatus::TimedOut => "timed out",
+            DayStatus::NoInput => "no input",
+            DayStatus::NotScaffolded => "not scaffolded",
+        }
+    }
+
+    /// `true` if the day's solution crashed, hung, returned an error or produced a wrong answer.
+    #[must_use]
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            DayStatus::WrongAnswer | DayStatus::Errored | DayStatus::Panicked | DayStatus::TimedOut
+        )
+    }
+}
+
+/// Outcome of running a set of days.
+pub struct RunSummary {
+    /// Timings of the run, if it was timed.
+    pub timings: Option<Timings>,
+    /// `false` if any part did not match its accepted answer. Always `true` if answers were not checked.
+    pub checks_passed: bool,
+    /// Status of every day that was run, ordered by day.
+    pub statuses: Vec<(Day, DayStatus)>,
+}
+
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    is_checked: bool,
+    timeout: Option<Duration>,
+) -> RunSummary {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut statuses: Vec<(Day, DayStatus)> = Vec::with_capacity(days_to_run.len());
+    let mut checks_passed = true;
+    // without answers a check would report every part as missing, a plain run can do without them.
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Could not read the answers from \"data/answers.json\": {e}");
+        if is_checked {
+            process::exit(1);
+        }
+        Answers::default()
+    });
 
     let mut need_space = false;
 
@@ -21,28 +100,172 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             }
             need_space = true;
 
+            let puzzle = PuzzleId::new(year, day);
+
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            // days linked into the registry run in-process with the profile of this build, others fall back to their binary.
+            let results = match registry::find(puzzle) {
+                Some(solution) => run_registered(solution, is_timed, timeout),
+                None => child_commands::run_solution(puzzle, is_timed, is_release, timeout)
+                    .unwrap_or_else(|e| {
+                        // a failure to build or spawn the binary only fails its own day.
+                        eprintln!("Could not run the solution for {puzzle}: {e:?}");
+                        Err(DayStatus::Panicked)
+                    }),
+            };
+
+            let status = match results {
+                Ok(results) => {
+                    if is_checked && !answers.print_check(puzzle, &results) {
+                        checks_passed = false;
+                    }
+                    timings.push(child_commands::timing_from_results(&results, puzzle));
+                    status_from_results(&answers, puzzle, &results)
+                }
+                Err(status) => {
+                    println!(
+                        "{ANSI_ITALIC}{}{ANSI_RESET}",
+                        status_message(status, timeout)
+                    );
+                    status
+                }
+            };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
+            statuses.push((day, status));
         });
 
-    if is_timed {
+    print_summary(&statuses, &timings);
+
+    let timings = if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            "{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
         Some(timings)
     } else {
         None
+    };
+
+    RunSummary {
+        timings,
+        checks_passed,
+        statuses,
+    }
+}
+
+/// Classify a day that ran to completion.
+fn status_from_results(answers: &Answers, puzzle: PuzzleId, results: &[PartResult]) -> DayStatus {
+    if results
+        .iter()
+        .any(|result| result.status == PartStatus::Errored)
+    {
+        DayStatus::Errored
+    } else if results
+        .iter()
+        .any(|result| matches!(answers.check(puzzle, result), CheckOutcome::Fail { .. }))
+    {
+        DayStatus::WrongAnswer
+    } else if results
+        .iter()
+        .filter(|result| !result.is_parse())
+        .all(|result| result.status != PartStatus::Solved)
+    {
+        DayStatus::Unsolved
+    } else {
+        DayStatus::Ok
+    }
+}
+
+fn status_message(status: DayStatus, timeout: Option<Duration>) -> String {
+    match (status, timeout) {
+        // the abandoned part may have left an unfinished line behind.
+        (DayStatus::TimedOut, Some(timeout)) => {
+            format!("\nTimed out after {timeout:.1?}, skipping.")
+        }
+        (DayStatus::Panicked, _) => "Panicked.".into(),
+        (DayStatus::NoInput, _) => "No input.".into(),
+        _ => "Not solved.".into(),
+    }
+}
+
+/// Print the status of every day, followed by the number of days per status.
+fn print_summary(statuses: &[(Day, DayStatus)], timings: &[Timing]) {
+    println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("------");
+    println!("{:<5} {:<16} {:<12} Part 2", "Day", "Status", "Part 1");
+
+    for (day, status) in statuses {
+        let timing = timings.iter().find(|t| t.puzzle.day == *day);
+        let part = |x: Option<&Option<String>>| x.and_then(Clone::clone).unwrap_or("-".into());
+        println!(
+            "{:<5} {:<16} {:<12} {}",
+            day.to_string(),
+            status.as_str(),
+            part(timing.map(|t| &t.part_1)),
+            part(timing.map(|t| &t.part_2)),
+        );
+    }
+
+    let mut counts: BTreeMap<DayStatus, usize> = BTreeMap::new();
+    for (_, status) in statuses {
+        *counts.entry(*status).or_default() += 1;
+    }
+
+    let counts = counts
+        .iter()
+        .map(|(status, count)| format!("{count} {}", status.as_str()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    println!("\n{ANSI_BOLD}Total (Days):{ANSI_RESET} {counts}");
+}
+
+/// Run a solution that is linked into the library against the day's input.
+///
+/// The solution runs on its own thread, so that a panic is contained and a hanging day can be abandoned.
+/// NOTE: an abandoned thread can not be stopped and keeps running in the background until the command exits.
+fn run_registered(
+    solution: &'static registry::Solution,
+    is_timed: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<PartResult>, DayStatus> {
+    let input = match try_read_file("inputs", solution.puzzle) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not open input file for {}: {e}", solution.puzzle);
+            return Err(DayStatus::NoInput);
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel();
+
+    let handle = thread::Builder::new()
+        .name(format!("day-{}", solution.puzzle))
+        .spawn(move || {
+            let results = (solution.run)(&input, is_timed);
+            let _ = sender.send(results);
+        })
+        .map_err(|_| DayStatus::Panicked)?;
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+
+    match received {
+        Ok(results) => {
+            let _ = handle.join();
+            Ok(results)
+        }
+        Err(RecvTimeoutError::Timeout) => Err(DayStatus::TimedOut),
+        // the sender is dropped without sending when the solution panics.
+        Err(RecvTimeoutError::Disconnected) => {
+            let _ = handle.join();
+            Err(DayStatus::Panicked)
+        }
     }
 }
 
@@ -60,46 +283,65 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading the results they report.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{get_path_for_bin, DayStatus, Error};
+    use crate::template::{
+        runner::{PartResult, PartStatus, PARSE_PART},
+        PuzzleId,
+    };
     use std::{
+        collections::HashMap,
         io::{BufRead, BufReader},
         path::Path,
         process::{Command, Stdio},
         thread,
+        time::{Duration, Instant},
     };
-
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    use tinyjson::JsonValue;
+
+    /// Run the solution bin for a given puzzle and collect the results reported for each part.
+    /// The child is killed if it does not exit within `timeout`.
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+        timeout: Option<Duration>,
+    ) -> Result<Result<Vec<PartResult>, DayStatus>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
+            return Ok(Err(DayStatus::NotScaffolded));
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
-
-        if is_release {
-            args.push("--release");
+        // the binary would panic without its input, report it the same way as a registered day.
+        let input_path = puzzle.data_path("inputs", "txt");
+        if !Path::new(&input_path).exists() {
+            eprintln!("Could not open input file for {puzzle}: {input_path} does not exist");
+            return Ok(Err(DayStatus::NoInput));
         }
 
+        // build first and run the binary itself, so that a timeout kills the solution and not only cargo.
+        let Some(executable) = build_solution(puzzle, is_release)? else {
+            return Ok(Err(DayStatus::Panicked));
+        };
+
+        // request structured results, the human-readable output moves to stderr.
+        let mut args = vec!["--json"];
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward stderr while grabbing results from stdout.
 
-        let mut cmd = Command::new("cargo")
+        let mut cmd = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -108,87 +350,131 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
+        let stderr_thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let stdout_thread = thread::spawn(move || {
+            let mut output = vec![];
+            for line in stdout.lines() {
+                let line = line.unwrap();
+                // anything that is not a result was printed by the solution itself.
+                match line.parse::<PartResult>() {
+                    Ok(result) => output.push(result),
+                    Err(_) => println!("{line}"),
+                }
+            }
+            output
+        });
+
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+        let exit_status = loop {
+            if let Some(exit_status) = cmd.try_wait()? {
+                break exit_status;
+            }
+
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                let _ = cmd.kill();
+                let _ = cmd.wait();
+                return Ok(Err(DayStatus::TimedOut));
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        let output = stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
+
+        if exit_status.success() {
+            Ok(Ok(output))
+        } else {
+            Ok(Err(DayStatus::Panicked))
         }
+    }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    /// Build the solution bin for a given puzzle and return the path of its executable, `None` if it does not compile.
+    fn build_solution(puzzle: PuzzleId, is_release: bool) -> Result<Option<String>, Error> {
+        let bin_name = puzzle.to_string();
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            &bin_name,
+            // compiler errors are still printed, the artifacts are reported as JSON on stdout.
+            "--message-format=json-render-diagnostics",
+        ];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        let executable = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.parse::<JsonValue>().ok())
+            .find_map(|message| {
+                let message = message.get::<HashMap<String, JsonValue>>()?;
+                message.get("executable")?.get::<String>().cloned()
+            });
 
-        Ok(output)
+        Ok(executable)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    pub fn timing_from_results(results: &[PartResult], puzzle: PuzzleId) -> super::Timing {
         let mut timings = super::Timing {
-            day,
+            puzzle,
+            parse: None,
+            parse_stats: None,
             part_1: None,
             part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_heap: None,
+            part_2_heap: None,
             total_nanos: 0_f64,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        for result in results {
+            if result.status != PartStatus::Solved {
+                continue;
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            let timing_str = Some(format!("{:.1?}", result.stats.mean));
+            let stats = Some(result.stats.clone());
+
+            match result.part {
+                PARSE_PART => (timings.parse, timings.parse_stats) = (timing_str, stats),
+                1 => {
+                    (timings.part_1, timings.part_1_stats) = (timing_str, stats);
+                    timings.part_1_heap = result.heap;
+                }
+                2 => {
+                    (timings.part_2, timings.part_2_stats) = (timing_str, stats);
+                    timings.part_2_heap = result.heap;
                 }
+                _ => continue,
+            }
 
-                timings.total_nanos += nanos;
-            });
+            #[allow(clippy::cast_precision_loss)]
+            {
+                timings.total_nanos += result.stats.mean.as_nanos() as f64;
+            }
+        }
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
     #[cfg(feature = "test_lib")]
     macro_rules! assert_approx_eq {
@@ -205,53 +491,178 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::timing_from_results;
+        use std::time::Duration;
+
+        use crate::{
+            puzzle,
+            template::{
+                heap::HeapStats,
+                runner::{BenchStats, PartResult, PartStatus},
+            },
+        };
 
-        use crate::day;
+        fn result(part: u8, answer: Option<&str>, nanos: u64) -> PartResult {
+            PartResult {
+                part,
+                answer: answer.map(Into::into),
+                error: None,
+                status: if answer.is_some() {
+                    PartStatus::Solved
+                } else {
+                    PartStatus::Unsolved
+                },
+                stats: BenchStats::single(Duration::from_nanos(nanos)),
+                heap: None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn collects_execution_times() {
+            let res = timing_from_results(
+                &[result(1, Some("0"), 74), result(2, Some("10"), 74_130_000)],
+                puzzle!(2023, 1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_1_stats.unwrap().mean, Duration::from_nanos(74));
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        fn collects_parse_times() {
+            let parse = PartResult {
+                status: PartStatus::Solved,
+                ..result(0, None, 1_000)
+            };
+            let res = timing_from_results(&[parse, result(1, Some("0"), 74)], puzzle!(2023, 1));
+            assert_approx_eq!(res.total_nanos, 1074_f64);
+            assert_eq!(res.parse.unwrap(), "1.0µs");
+            assert_eq!(res.parse_stats.unwrap().mean, Duration::from_nanos(1_000));
+            assert_eq!(res.part_2.is_none(), true);
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn skips_missing_parts() {
+            let res = timing_from_results(
+                &[result(1, None, 10), result(2, None, 10)],
+                puzzle!(2023, 1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.part_1_stats.is_none(), true);
+        }
+
+        #[test]
+        fn roundtrips_results_with_patterns_in_answer() {
+            let mut original = result(1, Some("@ @ ( ) ms\n(2s @ 5 samples)"), 2_000);
+            original.heap = Some(HeapStats {
+                allocations: 3,
+                allocated_bytes: 96,
+                peak_bytes: 64,
+            });
+            let line = tinyjson::JsonValue::from(&original).stringify().unwrap();
+            assert_eq!(line.contains('\n'), false);
+            let parsed = line.parse::<PartResult>().unwrap();
+            assert_eq!(parsed, original);
+        }
+
+        #[test]
+        fn ignores_lines_that_are_not_results() {
+            assert_eq!(
+                "Part 1: 0 (74.13ns @ 100000 samples)"
+                    .parse::<PartResult>()
+                    .is_err(),
+                true
+            );
+            assert_eq!("{}".parse::<PartResult>().is_err(), true);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{status_from_results, DayStatus};
+    use crate::{
+        puzzle,
+        template::{
+            answers::Answers,
+            runner::{BenchStats, PartResult, PartStatus},
+        },
+    };
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.map(Into::into),
+            error: None,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            stats: BenchStats::single(Duration::ZERO),
+            heap: None,
         }
     }
+
+    #[test]
+    fn classifies_results() {
+        let mut answers = Answers::default();
+        answers.set(puzzle!(2023, 1), 1, "209");
+
+        let status =
+            |results: &[PartResult]| status_from_results(&answers, puzzle!(2023, 1), results);
+
+        assert_eq!(
+            status(&[result(1, Some("209")), result(2, None)]),
+            DayStatus::Ok
+        );
+        assert_eq!(
+            status(&[result(1, Some("208")), result(2, Some("1"))]),
+            DayStatus::WrongAnswer
+        );
+        assert_eq!(
+            status(&[result(1, None), result(2, None)]),
+            DayStatus::WrongAnswer
+        );
+        assert_eq!(
+            status_from_results(
+                &answers,
+                puzzle!(2023, 2),
+                &[result(1, None), result(2, None)]
+            ),
+            DayStatus::Unsolved
+        );
+    }
+
+    #[test]
+    fn classifies_errors() {
+        let mut answers = Answers::default();
+        answers.set(puzzle!(2023, 1), 2, "42");
+
+        let errored = PartResult {
+            error: Some("expected a square input".into()),
+            status: PartStatus::Errored,
+            ..result(2, None)
+        };
+
+        assert_eq!(
+            status_from_results(&answers, puzzle!(2023, 1), &[result(1, Some("1")), errored]),
+            DayStatus::Errored
+        );
+        assert_eq!(DayStatus::Errored.is_failure(), true);
+    }
+
+    #[test]
+    fn flags_failures() {
+        assert_eq!(DayStatus::Ok.is_failure(), false);
+        assert_eq!(DayStatus::NotScaffolded.is_failure(), false);
+        assert_eq!(DayStatus::Panicked.is_failure(), true);
+        assert_eq!(DayStatus::TimedOut.is_failure(), true);
+        assert_eq!(DayStatus::WrongAnswer.is_failure(), true);
+    }
 }
@@ -1,64 +1,482 @@
 /// Encapsulates code that interacts with solution functions.
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
-use std::time::{Duration, Instant};
+use std::io::{stderr, stdout, Write};
+use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::{cmp, env, process};
 
+use tinyjson::JsonValue;
+
+use crate::template::answers::Answers;
+use crate::template::heap::{self, HeapStats};
+use crate::template::puzzle_client::{PuzzleClient, PuzzleClientError};
+use crate::template::submissions::{parse_wait_seconds, Submission, Submissions, Verdict};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{PuzzleId, ANSI_ITALIC, ANSI_RESET};
+
+/// The return type of a solution part.
+///
+/// Parts return `Option<T>`, where `None` means that the part is not solved yet,
+/// or `Result<T, E>` to report why a part failed, e.g. an input that does not meet the assumptions of the solution.
+pub trait PartOutput {
+    /// The answer of the part, `Ok(None)` if it is not solved yet or `Err` with the message of a failure.
+    fn to_answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn to_answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn to_answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+pub fn run_part<I: Copy, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) -> PartResult {
+    let is_timed = env::args().any(|x| x == "--time");
+    let result = solve_part(func, input, part, is_timed);
+
+    if is_json_mode() {
+        println!("{}", JsonValue::from(&result).stringify().unwrap());
+    }
+
+    if let Some(answer) = &result.answer {
+        if let Some(Err(e)) = submit_result(answer, puzzle, part) {
+            eprintln!("failed to submit result: {e}");
+        }
+    }
+
+    result
+}
+
+/// When run with `--check`, compare the results against the accepted answers in `data/answers.json`.
+/// Exits with a non-zero status if any part does not match.
+pub fn check_results(puzzle: PuzzleId, results: &[PartResult]) {
+    if !env::args().any(|x| x == "--check") {
+        return;
+    }
+
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Could not read the answers from \"data/answers.json\": {e}");
+        process::exit(1);
+    });
+
+    if !answers.print_check(puzzle, results) {
+        process::exit(1);
+    }
+}
+
+/// Run the shared parse function of a solution, the parts then borrow its output.
+pub fn run_parse<'a, P>(func: impl Fn(&'a str) -> P, input: &'a str) -> P {
+    let is_timed = env::args().any(|x| x == "--time");
+    let (parsed, result) = solve_parse(func, input, is_timed);
+
+    if is_json_mode() {
+        println!("{}", JsonValue::from(&result).stringify().unwrap());
+    }
+
+    parsed
+}
+
+/// Run the shared parse function of a solution and print its timing.
+/// The timing is reported as a [`PartResult`] of part [`PARSE_PART`].
+pub fn solve_parse<'a, P>(
+    func: impl Fn(&'a str) -> P,
+    input: &'a str,
+    is_timed: bool,
+) -> (P, PartResult) {
+    let (parsed, stats, heap) = run_timed(func, input, is_timed, |_| {
+        let mut out = human_output();
+        let _ = write!(out, "Parse");
+        let _ = out.flush();
+    });
+
+    let mut out = human_output();
+    let _ = writeln!(
+        out,
+        "\rParse{}",
+        format_duration(&stats.mean, stats.samples)
+    );
+
+    let stats_str = format_details(&stats, heap.as_ref());
+    if !stats_str.is_empty() {
+        let _ = writeln!(out, "{stats_str}");
+    }
+
+    let result = PartResult {
+        part: PARSE_PART,
+        answer: None,
+        error: None,
+        status: PartStatus::Solved,
+        stats,
+        heap,
+    };
+
+    (parsed, result)
+}
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Run a solution part, print its human-readable output and return the structured result.
+/// Shared by the standalone solution binaries and the in-process solution registry.
+pub fn solve_part<I: Copy, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    part: u8,
+    is_timed: bool,
+) -> PartResult {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, stats, heap) = run_timed(func, input, is_timed, |result| {
+        print_result(&result.to_answer(), &part_str, "", "");
+    });
+
+    let result = result.to_answer();
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&stats.mean, stats.samples),
+        &format_details(&stats, heap.as_ref()),
+    );
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let (answer, error, status) = match result {
+        Ok(Some(answer)) => (Some(answer), None, PartStatus::Solved),
+        Ok(None) => (None, None, PartStatus::Unsolved),
+        Err(e) => (None, Some(e), PartStatus::Errored),
+    };
+
+    PartResult {
+        part,
+        answer,
+        error,
+        status,
+        stats,
+        heap,
+    }
+}
+
+/// When run with `--json`, every part reports a [`PartResult`] as a single JSON line on stdout.
+/// The human-readable output is moved to stderr so that stdout stays machine-readable.
+fn is_json_mode() -> bool {
+    env::args().any(|x| x == "--json")
+}
+
+pub(crate) fn human_output() -> Box<dyn Write> {
+    if is_json_mode() {
+        Box::new(stderr())
+    } else {
+        Box::new(stdout())
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
+/// Outcome of a single solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    /// The part is not implemented yet, or did not find an answer.
+    Unsolved,
+    /// The part returned an error.
+    Errored,
+}
+
+impl PartStatus {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Errored => "error",
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "error" => Ok(PartStatus::Errored),
+            s => Err(format!("unknown part status `{s}`.")),
+        }
+    }
+}
+
+/// The shared parse step of a solution is reported as this part.
+pub const PARSE_PART: u8 = 0;
+
+/// Structured result of a solution part, exchanged between solution binaries and `run_multi`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    /// `1` or `2`, or [`PARSE_PART`] for the timing of the shared parse step.
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Message of the error returned by the part.
+    pub error: Option<String>,
+    pub status: PartStatus,
+    pub stats: BenchStats,
+    /// Heap usage of the cold run, only recorded with the `count-alloc` feature.
+    pub heap: Option<HeapStats>,
+}
+
+impl PartResult {
+    /// `true` if this is the timing of the shared parse step rather than a part.
+    #[must_use]
+    pub fn is_parse(&self) -> bool {
+        self.part == PARSE_PART
+    }
+}
+
+impl From<&PartResult> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        if let Some(error) = &value.error {
+            map.insert("error".into(), JsonValue::String(error.clone()));
+        }
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.stats.mean.as_nanos() as f64),
+        );
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+
+        if let Some(heap) = &value.heap {
+            map.insert("heap".into(), JsonValue::from(heap));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for PartResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part result to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected result.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected result.answer to be null or string.")?;
+
+        let error = json
+            .get("error")
+            .filter(|v| !v.is_null())
+            .map(|v| {
+                v.get::<String>()
+                    .ok_or("Expected result.error to be a string.")
+            })
+            .transpose()?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected result.status to be a string.")?
+            .parse()?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected result to have key `stats`.")
+            .map(BenchStats::try_from)??;
+
+        let heap = json
+            .get("heap")
+            .filter(|v| !v.is_null())
+            .map(HeapStats::try_from)
+            .transpose()?;
+
+        Ok(PartResult {
+            part,
+            answer: answer.cloned(),
+            error: error.cloned(),
+            status,
+            stats,
+            heap,
+        })
+    }
+}
+
+/// Summary of the timings collected for a single solution part.
+///
+/// When a part is not benched, every figure equals the cold run and `samples` is 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    /// Duration of the first, un-benched invocation.
+    pub cold: Duration,
+    pub samples: u128,
+    pub warmup: u128,
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Stats for a part that was only executed once.
+    #[must_use]
+    pub fn single(cold: Duration) -> Self {
+        Self {
+            mean: cold,
+            min: cold,
+            median: cold,
+            p95: cold,
+            std_dev: Duration::ZERO,
+            cold,
+            samples: 1,
+            warmup: 0,
+            outliers: 0,
+        }
+    }
+
+    /// Summarize bench samples, rejecting severe outliers outside of the 3 IQR fences.
+    ///
+    /// Warm-up iterations are expected to already be excluded from `timers`.
+    #[must_use]
+    pub fn from_samples(timers: &[Duration], cold: Duration, warmup: u128) -> Self {
+        if timers.is_empty() {
+            return Self::single(cold);
+        }
+
+        let mut sorted = timers.to_vec();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 25.0).as_nanos();
+        let q3 = percentile(&sorted, 75.0).as_nanos();
+        let iqr = q3 - q1;
+        let lower = q1.saturating_sub(iqr * 3);
+        let upper = q3 + iqr * 3;
+
+        let kept: Vec<Duration> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (lower..=upper).contains(&x.as_nanos()))
+            .collect();
+
+        let mean = average_duration(&kept);
+        #[allow(clippy::cast_precision_loss)]
+        let variance = kept
+            .iter()
+            .map(|x| (x.as_nanos() as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / kept.len() as f64;
+
+        Self {
+            #[allow(clippy::cast_possible_truncation)]
+            mean: Duration::from_nanos(mean as u64),
+            min: kept[0],
+            median: percentile(&kept, 50.0),
+            p95: percentile(&kept, 95.0),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+            cold,
+            samples: timers.len() as u128,
+            warmup,
+            outliers: (timers.len() - kept.len()) as u128,
+        }
+    }
+}
+
+/// Nearest-rank percentile of an ascending list of durations.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. by default, the function is executed once.
+///  2. when timed, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// Heap usage is recorded for the first invocation only.
+pub(crate) fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    is_timed: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, BenchStats, Option<HeapStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, heap) = heap::measure(|| {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
         func(input)
-    };
+    });
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let stats = if is_timed {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        BenchStats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    (result, stats, heap)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
+    let mut out = human_output();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let _ = write!(out, " > {ANSI_ITALIC}benching{ANSI_RESET}");
+    let _ = out.flush();
 
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
+    // warm caches and branch predictors before sampling, these runs are not part of the stats.
+    let warmup_iterations = (bench_iterations / 10).clamp(1, 1000);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input)));
+    }
+
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
@@ -67,11 +485,7 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers, *base_time, warmup_iterations)
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -90,49 +504,110 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// Formats the distribution and heap usage of a part, printed below the timing line.
+fn format_details(stats: &BenchStats, heap: Option<&HeapStats>) -> String {
+    [format_stats(stats), format_heap(heap)]
+        .into_iter()
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats the distribution of a benched part, printed below the timing line.
+fn format_stats(stats: &BenchStats) -> String {
+    if stats.samples == 1 {
+        return String::new();
+    }
+
+    format!(
+        "  ↳ min {:.1?}, median {:.1?}, p95 {:.1?}, σ {:.1?}, cold {:.1?} ({} warm-up, {} outliers)",
+        stats.min, stats.median, stats.p95, stats.std_dev, stats.cold, stats.warmup, stats.outliers
+    )
+}
+
+/// Formats the heap usage of a part, printed below the timing line.
+fn format_heap(heap: Option<&HeapStats>) -> String {
+    let Some(heap) = heap else {
+        return String::new();
+    };
+
+    format!(
+        "  ↳ heap: peak {}, {} allocations ({} total)",
+        heap::format_bytes(heap.peak_bytes),
+        heap.allocations,
+        heap::format_bytes(heap.allocated_bytes)
+    )
+}
+
+fn print_result(
+    result: &Result<Option<String>, String>,
+    part: &str,
+    duration_str: &str,
+    stats_str: &str,
+) {
     let is_intermediate_result = duration_str.is_empty();
+    let mut out = human_output();
+    let print_stats = |out: &mut Box<dyn Write>| {
+        if !stats_str.is_empty() {
+            let _ = writeln!(out, "{stats_str}");
+        }
+    };
 
     match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    let _ = write!(out, "{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    let _ = write!(out, "\r");
+                    let _ = writeln!(out, "{str}");
+                    print_stats(&mut out);
+                    let _ = writeln!(out, "{result}");
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    let _ = write!(out, "{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    let _ = write!(out, "\r");
+                    let _ = writeln!(out, "{str}");
+                    print_stats(&mut out);
                 }
             }
         }
-        None => {
+        Ok(None) => {
+            if is_intermediate_result {
+                let _ = write!(out, "{part}: ✖");
+            } else {
+                let _ = write!(out, "\r");
+                let _ = writeln!(out, "{part}: ✖             ");
+            }
+        }
+        Err(e) => {
+            // the error takes the place of the answer.
+            let str = format!("{part}: {ANSI_BOLD}error:{ANSI_RESET} {e}");
             if is_intermediate_result {
-                print!("{part}: ✖");
+                let _ = write!(out, "{str}");
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                let _ = write!(out, "\r");
+                let _ = writeln!(out, "{str}{duration_str}");
             }
         }
     }
+
+    let _ = out.flush();
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. the puzzle client selected by `AOC_CLIENT` can be created, see [`PuzzleClient::from_env`].
+///  3. the submission log can be read and does not block the answer, e.g. because it was already rejected.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<String, PuzzleClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,11 +630,143 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let client = match PuzzleClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to create puzzle client: {e}");
+            process::exit(1);
+        }
+    };
+
+    let answer = result.to_string();
+    let mut submissions = Submissions::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Refusing to submit part {part}, could not read \"data/submissions.json\": {e}");
+        process::exit(1);
+    });
+    let now = unix_now();
+
+    if let Err(blocked) = submissions.guard(puzzle, part, &answer, now) {
+        eprintln!("Refusing to submit part {part}: {blocked}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let message = match client.submit(puzzle, part, &answer) {
+        Ok(message) => message,
+        Err(e) => return Some(Err(e)),
+    };
+
+    let verdict = Verdict::parse(&message);
+
+    submissions.data.push(Submission {
+        puzzle,
+        part,
+        answer: answer.clone(),
+        time: now,
+        verdict,
+        wait_seconds: parse_wait_seconds(&message),
+    });
+
+    if let Err(e) = submissions.store_file() {
+        eprintln!("Failed to store submission log: {e}");
+    }
+
+    if verdict == Verdict::Correct {
+        // an unreadable file is left alone, storing it would drop the answers recorded so far.
+        match Answers::read_from_file() {
+            Ok(mut answers) => {
+                answers.set(puzzle, part, &answer);
+                if let Err(e) = answers.store_file() {
+                    eprintln!("Failed to store accepted answer: {e}");
+                }
+            }
+            Err(e) => eprintln!(
+                "Failed to store accepted answer, could not read \"data/answers.json\": {e}"
+            ),
+        }
+    }
+
+    Some(Ok(message))
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BenchStats, PartOutput, PartResult, PartStatus};
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let stats = BenchStats::from_samples(
+            &nanos(&[10, 12, 11, 13, 14, 10, 12, 11, 13, 14]),
+            Duration::from_nanos(100),
+            2,
+        );
+        assert_eq!(stats.mean, Duration::from_nanos(12));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(12));
+        assert_eq!(stats.p95, Duration::from_nanos(14));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1));
+        assert_eq!(stats.cold, Duration::from_nanos(100));
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.warmup, 2);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(
+            &nanos(&[10, 10, 11, 11, 12, 12, 10, 11, 12, 5000]),
+            Duration::from_nanos(100),
+            1,
+        );
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(11));
+        assert_eq!(stats.p95, Duration::from_nanos(12));
+        assert_eq!(stats.samples, 10);
+    }
+
+    #[test]
+    fn handles_single_runs() {
+        let stats = BenchStats::from_samples(&[], Duration::from_nanos(100), 0);
+        assert_eq!(stats, BenchStats::single(Duration::from_nanos(100)));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.median, Duration::from_nanos(100));
+    }
+
+    #[test]
+    fn converts_part_outputs() {
+        assert_eq!(Some(42).to_answer(), Ok(Some("42".into())));
+        assert_eq!(None::<u32>.to_answer(), Ok(None));
+        assert_eq!(Ok::<u32, String>(42).to_answer(), Ok(Some("42".into())));
+        assert_eq!(
+            Err::<u32, &str>("no path to the exit").to_answer(),
+            Err("no path to the exit".into())
+        );
+    }
+
+    #[test]
+    fn roundtrips_errored_results() {
+        let result = PartResult {
+            part: 2,
+            answer: None,
+            error: Some("expected a square input".into()),
+            status: PartStatus::Errored,
+            stats: BenchStats::single(Duration::from_nanos(100)),
+            heap: None,
+        };
+        let json = JsonValue::from(&result).stringify().unwrap();
+        assert_eq!(json.parse::<PartResult>(), Ok(result));
+    }
 }
@@ -0,0 +1,506 @@
+/// Keeps a log of every submitted answer and guards against submissions that are known to be wrong.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    puzzle_id::{insert_puzzle_json, puzzle_from_json},
+    PuzzleId,
+};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// The response of the puzzle server to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// The answer was submitted too recently after a previous one and was not checked.
+    Wait,
+    AlreadyCompleted,
+    Unknown,
+}
+
+impl Verdict {
+    /// Parse the verdict from the server's message, as returned by the puzzle client.
+    #[must_use]
+    pub fn parse(output: &str) -> Self {
+        let output = output.to_lowercase();
+
+        if output.contains("that's the right answer") {
+            Verdict::Correct
+        } else if output.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if output.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if output.contains("that's not the right answer") {
+            Verdict::Incorrect
+        } else if output.contains("you gave an answer too recently") {
+            Verdict::Wait
+        } else if output.contains("did you already complete it") {
+            Verdict::AlreadyCompleted
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::Wait => "wait",
+            Verdict::AlreadyCompleted => "already_completed",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    fn is_rejected(self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Incorrect,
+            Verdict::Wait,
+            Verdict::AlreadyCompleted,
+            Verdict::Unknown,
+        ]
+        .into_iter()
+        .find(|v| v.as_str() == s)
+        .ok_or(format!("unknown verdict `{s}`."))
+    }
+}
+
+/// Parse the time the server asks to wait before the next submission, in seconds.
+///
+/// Handles both `Please wait one minute before trying again.` and `You have 4m 30s left to wait.`.
+#[must_use]
+pub fn parse_wait_seconds(output: &str) -> Option<u64> {
+    let output = output.to_lowercase();
+
+    if let Some((_, rest)) = output.split_once("you have ") {
+        let (remaining, _) = rest.split_once(" left to wait")?;
+        return remaining
+            .split_whitespace()
+            .map(|x| {
+                let (value, unit) = x.split_at(x.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+
+    let (_, rest) = output.split_once("please wait ")?;
+    let (amount, _) = rest.split_once(" before trying again")?;
+    let (value, unit) = amount.split_once(' ')?;
+
+    let value = match value {
+        "one" => 1,
+        value => value.parse().ok()?,
+    };
+
+    match unit {
+        "second" | "seconds" => Some(value),
+        "minute" | "minutes" => Some(value * 60),
+        _ => None,
+    }
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the unix epoch.
+    pub time: u64,
+    pub verdict: Verdict,
+    /// Seconds to wait before the next submission, if the server asked for it.
+    pub wait_seconds: Option<u64>,
+}
+
+/// The reason a submission was refused before reaching the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Blocked {
+    AlreadyRejected,
+    TooHigh { bound: String },
+    TooLow { bound: String },
+    Cooldown { remaining_seconds: u64 },
+}
+
+impl Display for Blocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Blocked::AlreadyRejected => write!(f, "this answer was already rejected."),
+            Blocked::TooHigh { bound } => {
+                write!(f, "answer is not below {bound}, which was too high.")
+            }
+            Blocked::TooLow { bound } => {
+                write!(f, "answer is not above {bound}, which was too low.")
+            }
+            Blocked::Cooldown { remaining_seconds } => write!(
+                f,
+                "the previous submission asked to wait, {remaining_seconds}s left."
+            ),
+        }
+    }
+}
+
+/// Represents the submission log for all days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty log.
+    ///
+    /// A file that cannot be read or parsed is an error, an empty log would let every submission through and be
+    /// overwritten by the next one.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(SUBMISSIONS_FILE_PATH) {
+            Ok(json) => Submissions::try_from(json),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Check an answer against earlier submissions for the same part.
+    pub fn guard(&self, puzzle: PuzzleId, part: u8, answer: &str, now: u64) -> Result<(), Blocked> {
+        // the server enforces the wait time for the whole puzzle, not just the part.
+        let cooldown_end = self
+            .data
+            .iter()
+            .filter(|s| s.puzzle == puzzle)
+            .filter_map(|s| s.wait_seconds.map(|wait| s.time + wait))
+            .max();
+
+        if let Some(end) = cooldown_end.filter(|end| *end > now) {
+            return Err(Blocked::Cooldown {
+                remaining_seconds: end - now,
+            });
+        }
+
+        let previous: Vec<&Submission> = self
+            .data
+            .iter()
+            .filter(|s| s.puzzle == puzzle && s.part == part)
+            .collect();
+
+        if previous
+            .iter()
+            .any(|s| s.verdict.is_rejected() && s.answer == answer)
+        {
+            return Err(Blocked::AlreadyRejected);
+        }
+
+        // bounds only apply to numeric answers.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            previous
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        if let Some(bound) = bound(Verdict::TooHigh).min().filter(|b| value >= *b) {
+            return Err(Blocked::TooHigh {
+                bound: bound.to_string(),
+            });
+        }
+
+        if let Some(bound) = bound(Verdict::TooLow).max().filter(|b| value <= *b) {
+            return Err(Blocked::TooLow {
+                bound: bound.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        insert_puzzle_json(&mut map, value.puzzle);
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert("time".into(), JsonValue::Number(value.time as f64));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+        map.insert(
+            "wait_seconds".into(),
+            match value.wait_seconds {
+                Some(x) => JsonValue::Number(x as f64),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let puzzle = puzzle_from_json(json, "submission")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let time = json
+            .get("time")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected submission.time to be a number.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        let wait_seconds = json
+            .get("wait_seconds")
+            .map(|v| if v.is_null() { None } else { v.get::<f64>() })
+            .ok_or("Expected submission.wait_seconds to be null or number.")?
+            .map(|x| *x as u64);
+
+        Ok(Submission {
+            puzzle,
+            part,
+            answer: answer.clone(),
+            time,
+            verdict,
+            wait_seconds,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_wait_seconds, Blocked, Submission, Submissions, Verdict};
+    use crate::puzzle;
+
+    fn submission(part: u8, answer: &str, time: u64, verdict: Verdict) -> Submission {
+        Submission {
+            puzzle: puzzle!(2023, 1),
+            part,
+            answer: answer.into(),
+            time,
+            verdict,
+            wait_seconds: if verdict.is_rejected() {
+                Some(60)
+            } else {
+                None
+            },
+        }
+    }
+
+    fn get_mock_submissions() -> Submissions {
+        Submissions {
+            data: vec![
+                submission(1, "500", 1000, Verdict::TooHigh),
+                submission(1, "100", 2000, Verdict::TooLow),
+                submission(1, "300", 3000, Verdict::Incorrect),
+                submission(1, "700", 3010, Verdict::Wait),
+            ],
+        }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::parse("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(Verdict::parse("That's not the right answer; your answer is too high. Please wait one minute before trying again."), Verdict::TooHigh);
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer. If you're stuck, ..."),
+            Verdict::Incorrect
+        );
+        assert_eq!(Verdict::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait."), Verdict::Wait);
+        assert_eq!(
+            Verdict::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::AlreadyCompleted
+        );
+        assert_eq!(
+            Verdict::parse("error: something went wrong"),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(
+            parse_wait_seconds("Please wait one minute before trying again."),
+            Some(60)
+        );
+        assert_eq!(
+            parse_wait_seconds("please wait 5 minutes before trying again."),
+            Some(300)
+        );
+        assert_eq!(parse_wait_seconds("You have 34s left to wait."), Some(34));
+        assert_eq!(
+            parse_wait_seconds("You have 4m 30s left to wait."),
+            Some(270)
+        );
+        assert_eq!(parse_wait_seconds("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn blocks_rejected_answers() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            submissions.guard(puzzle!(2023, 1), 1, "300", 5000),
+            Err(Blocked::AlreadyRejected)
+        );
+    }
+
+    #[test]
+    fn blocks_answers_outside_of_bounds() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            submissions.guard(puzzle!(2023, 1), 1, "501", 5000),
+            Err(Blocked::TooHigh {
+                bound: "500".into()
+            })
+        );
+        assert_eq!(
+            submissions.guard(puzzle!(2023, 1), 1, "99", 5000),
+            Err(Blocked::TooLow {
+                bound: "100".into()
+            })
+        );
+        assert_eq!(submissions.guard(puzzle!(2023, 1), 1, "250", 5000), Ok(()));
+        assert_eq!(
+            submissions.guard(puzzle!(2023, 1), 1, "seven", 5000),
+            Ok(())
+        );
+        assert_eq!(submissions.guard(puzzle!(2023, 1), 2, "501", 5000), Ok(()));
+    }
+
+    #[test]
+    fn blocks_submissions_during_cooldown() {
+        let submissions = get_mock_submissions();
+        assert_eq!(
+            submissions.guard(puzzle!(2023, 1), 2, "1", 3020),
+            Err(Blocked::Cooldown {
+                remaining_seconds: 40
+            })
+        );
+        assert_eq!(submissions.guard(puzzle!(2023, 1), 2, "1", 3060), Ok(()));
+        assert_eq!(submissions.guard(puzzle!(2023, 2), 1, "1", 3020), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = get_mock_submissions();
+        let json = tinyjson::JsonValue::from(submissions.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+        assert_eq!(parsed.data, submissions.data);
+    }
+}
@@ -1,16 +1,28 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    heap::HeapStats,
+    puzzle_id::{insert_puzzle_json, puzzle_from_json},
+    runner::BenchStats,
+    PuzzleId, Year,
+};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
-/// Represents benchmark times for a single day.
+/// Represents benchmark times for a single puzzle.
 #[derive(Clone, Debug)]
 pub struct Timing {
-    pub day: Day,
+    pub puzzle: PuzzleId,
+    /// Timing of the shared parse step, if the solution has one.
+    pub parse: Option<String>,
+    pub parse_stats: Option<BenchStats>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    pub part_1_heap: Option<HeapStats>,
+    pub part_2_heap: Option<HeapStats>,
     pub total_nanos: f64,
 }
 
@@ -31,10 +43,14 @@ impl Timings {
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        Timings::read_from_path(TIMINGS_FILE_PATH).unwrap_or_default()
+    }
+
+    /// Rehydrate timings from the JSON file at `path`.
+    pub fn read_from_path(path: &str) -> Result<Self, String> {
+        fs::read_to_string(path)
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
-            .unwrap_or_default()
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
@@ -46,24 +62,46 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle == timing.puzzle) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.puzzle);
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        // NOTE: fold from `0.0`, the sum of an empty list of floats is `-0.0`.
+        self.data.iter().fold(0.0, |acc, x| acc + x.total_nanos) / 1_000_000_f64
+    }
+
+    /// All years that have timings, in ascending order.
+    pub fn years(&self) -> Vec<Year> {
+        let mut years: Vec<Year> = self.data.iter().map(|t| t.puzzle.year).collect();
+        years.sort_unstable();
+        years.dedup();
+        years
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    /// The timings of a single year.
+    #[must_use]
+    pub fn for_year(&self, year: Year) -> Self {
+        Timings {
+            data: self
+                .data
+                .iter()
+                .filter(|t| t.puzzle.year == year)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn is_day_complete(&self, puzzle: PuzzleId) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.puzzle == puzzle && t.part_1.is_some() && t.part_2.is_some())
     }
 }
 
@@ -111,9 +149,17 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        insert_puzzle_json(&mut map, value.puzzle);
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::String(parse.clone()));
+        }
+
+        if let Some(stats) = &value.parse_stats {
+            map.insert("parse_stats".into(), JsonValue::from(stats));
+        }
+
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
 
@@ -133,6 +179,22 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        if let Some(stats) = &value.part_1_stats {
+            map.insert("part_1_stats".into(), JsonValue::from(stats));
+        }
+
+        if let Some(stats) = &value.part_2_stats {
+            map.insert("part_2_stats".into(), JsonValue::from(stats));
+        }
+
+        if let Some(heap) = &value.part_1_heap {
+            map.insert("part_1_heap".into(), JsonValue::from(heap));
+        }
+
+        if let Some(heap) = &value.part_2_heap {
+            map.insert("part_2_heap".into(), JsonValue::from(heap));
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -145,11 +207,7 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
+        let puzzle = puzzle_from_json(json, "timing")?;
 
         let part_1 = json
             .get("part_1")
@@ -161,15 +219,62 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // the parse step is optional, most solutions parse their input in each part.
+        let parse = json
+            .get("parse")
+            .filter(|v| !v.is_null())
+            .map(|v| {
+                v.get::<String>()
+                    .ok_or("Expected timing.parse to be a string.")
+            })
+            .transpose()?;
+
+        let parse_stats = json
+            .get("parse_stats")
+            .filter(|v| !v.is_null())
+            .map(BenchStats::try_from)
+            .transpose()?;
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // stats are optional, timings stored by older versions do not have them.
+        let part_1_stats = json
+            .get("part_1_stats")
+            .filter(|v| !v.is_null())
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_2_stats = json
+            .get("part_2_stats")
+            .filter(|v| !v.is_null())
+            .map(BenchStats::try_from)
+            .transpose()?;
+
+        let part_1_heap = json
+            .get("part_1_heap")
+            .filter(|v| !v.is_null())
+            .map(HeapStats::try_from)
+            .transpose()?;
+
+        let part_2_heap = json
+            .get("part_2_heap")
+            .filter(|v| !v.is_null())
+            .map(HeapStats::try_from)
+            .transpose()?;
+
         Ok(Timing {
-            day,
+            puzzle,
+            parse: parse.cloned(),
+            parse_stats,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats,
+            part_2_stats,
+            part_1_heap,
+            part_2_heap,
             total_nanos,
         })
     }
@@ -177,9 +282,118 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+#[allow(clippy::cast_precision_loss)]
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let durations = [
+            ("mean_nanos", value.mean),
+            ("min_nanos", value.min),
+            ("median_nanos", value.median),
+            ("p95_nanos", value.p95),
+            ("std_dev_nanos", value.std_dev),
+            ("cold_nanos", value.cold),
+        ];
+
+        for (key, duration) in durations {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        let counts = [
+            ("samples", value.samples),
+            ("warmup", value.warmup),
+            ("outliers", value.outliers),
+        ];
+
+        for (key, count) in counts {
+            map.insert(key.into(), JsonValue::Number(count as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|x| x as u64)
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            mean: Duration::from_nanos(number("mean_nanos")?),
+            min: Duration::from_nanos(number("min_nanos")?),
+            median: Duration::from_nanos(number("median_nanos")?),
+            p95: Duration::from_nanos(number("p95_nanos")?),
+            std_dev: Duration::from_nanos(number("std_dev_nanos")?),
+            cold: Duration::from_nanos(number("cold_nanos")?),
+            samples: number("samples")?.into(),
+            warmup: number("warmup")?.into(),
+            outliers: number("outliers")?.into(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&HeapStats> for JsonValue {
+    fn from(value: &HeapStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let counts = [
+            ("allocations", value.allocations),
+            ("allocated_bytes", value.allocated_bytes),
+            ("peak_bytes", value.peak_bytes),
+        ];
+
+        for (key, count) in counts {
+            map.insert(key.into(), JsonValue::Number(count as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HeapStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected heap stats to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|x| x as u64)
+                .ok_or(format!("Expected heap stats.{key} to be a number."))
+        };
+
+        Ok(HeapStats {
+            allocations: number("allocations")?,
+            allocated_bytes: number("allocated_bytes")?,
+            peak_bytes: number("peak_bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::puzzle;
 
     use super::{Timing, Timings};
 
@@ -187,21 +401,39 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2023, 2),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: puzzle!(2023, 4),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 4e+10,
                 },
             ],
@@ -209,20 +441,32 @@ mod tests {
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{puzzle, template::timings::Timings};
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2023", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
-            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.puzzle, puzzle!(2023, 1));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_bench_stats() {
+            let json = r#"{ "data": [{ "year": "2023", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "mean_nanos": 1000000, "min_nanos": 900000, "median_nanos": 950000, "p95_nanos": 1200000, "std_dev_nanos": 5000, "cold_nanos": 3000000, "samples": 100, "warmup": 10, "outliers": 2 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.median.as_nanos(), 950_000);
+            assert_eq!(stats.cold.as_nanos(), 3_000_000);
+            assert_eq!(stats.outliers, 2);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,9 +491,36 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
-        use std::collections::HashMap;
+        use crate::template::{heap::HeapStats, runner::BenchStats, timings::Timings};
+        use std::{collections::HashMap, time::Duration};
         use tinyjson::JsonValue;
 
+        #[test]
+        fn roundtrips_bench_stats() {
+            let mut timings = get_mock_timings();
+            let stats = BenchStats::single(Duration::from_micros(5));
+            timings.data[0].part_1_stats = Some(stats.clone());
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1_stats, Some(stats));
+            assert_eq!(parsed.data[0].part_2_stats, None);
+        }
+
+        #[test]
+        fn roundtrips_heap_stats() {
+            let mut timings = get_mock_timings();
+            let heap = HeapStats {
+                allocations: 12,
+                allocated_bytes: 4096,
+                peak_bytes: 1536,
+            };
+            timings.data[1].part_2_heap = Some(heap);
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[1].part_2_heap, Some(heap));
+            assert_eq!(parsed.data[1].part_1_heap, None);
+        }
+
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
@@ -270,7 +541,7 @@ mod tests {
 
     mod is_day_complete {
         use crate::{
-            day,
+            puzzle,
             template::timings::{Timing, Timings},
         };
 
@@ -278,49 +549,68 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(puzzle!(2023, 1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2023, 1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2023, 1)), false);
         }
     }
 
     mod merge {
         use crate::{
-            day,
+            puzzle,
             template::timings::{Timing, Timings},
+            year,
         };
 
         use super::get_mock_timings;
@@ -330,18 +620,24 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
+                    puzzle: puzzle!(2023, 3),
+                    parse: None,
+                    parse_stats: None,
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 0_f64,
                 }],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[2].day, day!(3));
-            assert_eq!(merged.data[3].day, day!(4));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2023, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2023, 2));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2023, 3));
+            assert_eq!(merged.data[3].puzzle, puzzle!(2023, 4));
         }
 
         #[test]
@@ -350,19 +646,25 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2023, 2),
+                    parse: None,
+                    parse_stats: None,
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
                     total_nanos: 0_f64,
                 }],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2023, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2023, 2));
             assert_eq!(merged.data[1].total_nanos, 0_f64);
-            assert_eq!(merged.data[2].day, day!(4));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2023, 4));
         }
 
         #[test]
@@ -380,5 +682,18 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        #[test]
+        fn keeps_days_of_other_years() {
+            let timings = get_mock_timings();
+            let mut other = get_mock_timings();
+            other.data[0].puzzle = puzzle!(2022, 1);
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].puzzle, puzzle!(2022, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2023, 1));
+            assert_eq!(merged.years(), vec![year!(2022), year!(2023)]);
+            assert_eq!(merged.for_year(year!(2022)).data.len(), 1);
+        }
     }
 }
@@ -0,0 +1,297 @@
+/// Compares a fresh benchmark run against stored timings, to catch regressions when refactoring.
+use std::time::Duration;
+
+use crate::template::{
+    runner::{BenchStats, PARSE_PART},
+    timings::{Timing, Timings},
+    PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// z-score of a two-sided 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+/// Benchmark figures of a single part, in nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Sample {
+    mean: f64,
+    std_dev: f64,
+    samples: f64,
+}
+
+impl Sample {
+    #[allow(clippy::cast_precision_loss)]
+    fn from_stats(stats: &BenchStats) -> Self {
+        Sample {
+            mean: stats.mean.as_nanos() as f64,
+            std_dev: stats.std_dev.as_nanos() as f64,
+            samples: stats.samples.max(1) as f64,
+        }
+    }
+
+    /// Timings stored by older versions only have the formatted mean, without any spread.
+    fn of_part(timing: &Timing, part: u8) -> Option<Self> {
+        let (formatted, stats) = match part {
+            PARSE_PART => (&timing.parse, &timing.parse_stats),
+            1 => (&timing.part_1, &timing.part_1_stats),
+            _ => (&timing.part_2, &timing.part_2_stats),
+        };
+
+        if let Some(stats) = stats {
+            return Some(Sample::from_stats(stats));
+        }
+
+        formatted
+            .as_deref()
+            .and_then(parse_duration)
+            .map(|mean| Sample {
+                mean: mean.as_secs_f64() * 1e9,
+                std_dev: 0.0,
+                samples: 1.0,
+            })
+    }
+}
+
+/// Parse a duration formatted by its `Debug` implementation, e.g. `39.0ns` or `1.2ms`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let units = [("ns", 1e-9), ("µs", 1e-6), ("ms", 1e-3), ("s", 1.0)];
+
+    units.iter().find_map(|(unit, factor)| {
+        let value: f64 = s.strip_suffix(unit)?.trim().parse().ok()?;
+        Duration::try_from_secs_f64(value * factor).ok()
+    })
+}
+
+/// The timings of a part before and after a change.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartComparison {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    before: Sample,
+    after: Sample,
+}
+
+impl PartComparison {
+    /// Relative change of the mean, `0.1` means the part got 10% slower.
+    #[must_use]
+    pub fn change(&self) -> f64 {
+        (self.after.mean - self.before.mean) / self.before.mean
+    }
+
+    /// Half-width of the 95% confidence interval of [`Self::change`].
+    /// Returns `None` if the spread of neither run is known.
+    #[must_use]
+    pub fn margin(&self) -> Option<f64> {
+        let variance = |s: &Sample| s.std_dev.powi(2) / s.samples;
+        let standard_error = (variance(&self.before) + variance(&self.after)).sqrt();
+
+        if standard_error > 0.0 {
+            Some(Z_95 * standard_error / self.before.mean)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the change exceeds the noise of the measurements.
+    /// Changes without a known spread are taken at face value.
+    #[must_use]
+    pub fn is_significant(&self) -> bool {
+        self.margin()
+            .is_none_or(|margin| self.change().abs() > margin)
+    }
+
+    /// Whether the part got significantly slower by more than `threshold`, e.g. `0.05` for 5%.
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.is_significant() && self.change() > threshold
+    }
+}
+
+/// Compare every part that is present in both sets of timings.
+#[must_use]
+pub fn compare(before: &Timings, after: &Timings) -> Vec<PartComparison> {
+    let mut comparisons = vec![];
+
+    for timing in &after.data {
+        let Some(previous) = before.data.iter().find(|t| t.puzzle == timing.puzzle) else {
+            continue;
+        };
+
+        for part in [PARSE_PART, 1, 2] {
+            let (Some(before), Some(after)) = (
+                Sample::of_part(previous, part),
+                Sample::of_part(timing, part),
+            ) else {
+                continue;
+            };
+
+            if before.mean > 0.0 {
+                comparisons.push(PartComparison {
+                    puzzle: timing.puzzle,
+                    part,
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+
+    comparisons.sort_by_key(|c| (c.puzzle, c.part));
+    comparisons
+}
+
+/// Print a table of the comparisons, returns `true` if any part regressed past `threshold`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn print(comparisons: &[PartComparison], source: &str, threshold: f64) -> bool {
+    println!();
+    println!("{ANSI_BOLD}Comparison{ANSI_RESET} against {source}");
+    println!("------");
+
+    if comparisons.is_empty() {
+        println!("{ANSI_ITALIC}No stored timings of the benched parts.{ANSI_RESET}");
+        return false;
+    }
+
+    let format_nanos = |nanos: f64| format!("{:.1?}", Duration::from_nanos(nanos as u64));
+
+    println!(
+        "{:<10} {:<5} {:<12} {:<12} {:<20} Verdict",
+        "Puzzle", "Part", "Before", "After", "Change"
+    );
+
+    let mut regressions = 0;
+
+    for comparison in comparisons {
+        let is_regression = comparison.is_regression(threshold);
+        if is_regression {
+            regressions += 1;
+        }
+
+        let verdict = if is_regression {
+            "regressed"
+        } else if !comparison.is_significant() {
+            "~"
+        } else if comparison.change() < 0.0 {
+            "faster"
+        } else {
+            "slower"
+        };
+
+        println!(
+            "{:<10} {:<5} {:<12} {:<12} {:<20} {verdict}",
+            comparison.puzzle.to_string(),
+            if comparison.part == PARSE_PART {
+                "parse".to_string()
+            } else {
+                comparison.part.to_string()
+            },
+            format_nanos(comparison.before.mean),
+            format_nanos(comparison.after.mean),
+            format_change(comparison),
+        );
+    }
+
+    println!();
+    if regressions > 0 {
+        println!(
+            "{ANSI_BOLD}{regressions} part(s) regressed by more than {:.1}%.{ANSI_RESET}",
+            threshold * 100.0
+        );
+    } else {
+        println!("No part regressed by more than {:.1}%.", threshold * 100.0);
+    }
+
+    regressions > 0
+}
+
+/// e.g. `+12.3% (±2.1%)`, the margin is left out if it is unknown.
+fn format_change(comparison: &PartComparison) -> String {
+    let change = format!("{:+.1}%", comparison.change() * 100.0);
+    match comparison.margin() {
+        Some(margin) => format!("{change} (±{:.1}%)", margin * 100.0),
+        None => change,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, format_change, parse_duration};
+    use crate::{
+        puzzle,
+        template::{
+            runner::BenchStats,
+            timings::{Timing, Timings},
+        },
+    };
+
+    fn stats(mean: u64, std_dev: u64) -> BenchStats {
+        BenchStats {
+            mean: Duration::from_nanos(mean),
+            std_dev: Duration::from_nanos(std_dev),
+            samples: 100,
+            ..BenchStats::single(Duration::from_nanos(mean))
+        }
+    }
+
+    fn timings(part_1: BenchStats, part_2: Option<&str>) -> Timings {
+        Timings {
+            data: vec![Timing {
+                puzzle: puzzle!(2023, 1),
+                parse: None,
+                parse_stats: None,
+                part_1: Some(format!("{:.1?}", part_1.mean)),
+                part_2: part_2.map(ToString::to_string),
+                part_1_stats: Some(part_1),
+                part_2_stats: None,
+                part_1_heap: None,
+                part_2_heap: None,
+                total_nanos: 0.0,
+            }],
+        }
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("39.0ns"), Some(Duration::from_nanos(39)));
+        assert_eq!(parse_duration("6.5µs"), Some(Duration::from_nanos(6500)));
+        assert_eq!(parse_duration("1.2ms"), Some(Duration::from_micros(1200)));
+        assert_eq!(parse_duration("2.0s"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_duration("abc"), None);
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let before = timings(stats(1000, 10), Some("2.0ms"));
+        let after = timings(stats(1200, 10), Some("2.1ms"));
+
+        let comparisons = compare(&before, &after);
+        assert_eq!(comparisons.len(), 2);
+
+        let part_1 = &comparisons[0];
+        assert!((part_1.change() - 0.2).abs() < 1e-9);
+        assert!(part_1.is_significant());
+        assert!(part_1.is_regression(0.05));
+        assert!(!part_1.is_regression(0.25));
+        assert_eq!(format_change(part_1), "+20.0% (±0.3%)");
+
+        // part 2 has no stats, so its change is taken at face value.
+        let part_2 = &comparisons[1];
+        assert_eq!(part_2.margin(), None);
+        assert!(part_2.is_regression(0.04));
+        assert_eq!(format_change(part_2), "+5.0%");
+    }
+
+    #[test]
+    fn ignores_noise() {
+        let before = timings(stats(1000, 400), None);
+        let after = timings(stats(1080, 400), None);
+
+        let comparisons = compare(&before, &after);
+        assert_eq!(comparisons.len(), 1);
+        assert!(!comparisons[0].is_significant());
+        assert!(!comparisons[0].is_regression(0.05));
+    }
+}
@@ -0,0 +1,438 @@
+/// Keeps a log of every stored benchmark run, so that the performance of a day can be followed over time.
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{Error, ErrorKind},
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    timings::{Timing, Timings},
+    PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+static HISTORY_FILE_PATH: &str = "./data/timings_history.json";
+
+/// Describes the code and machine a benchmark run was recorded with.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunInfo {
+    /// Seconds since the unix epoch.
+    pub time: u64,
+    /// Short hash of the checked out commit, with a `-dirty` suffix if there were uncommitted changes.
+    pub commit: Option<String>,
+    pub rustc: Option<String>,
+    pub cpu: Option<String>,
+    /// Build profile of the benchmarked code, `release` or `debug`.
+    pub profile: String,
+}
+
+impl RunInfo {
+    /// Collect information about the current run, whose solutions were built in release mode if `is_release` is set.
+    #[must_use]
+    pub fn current(is_release: bool) -> Self {
+        RunInfo {
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|x| x.as_secs())
+                .unwrap_or_default(),
+            commit: git_commit(),
+            rustc: command_output("rustc", &["--version"]),
+            cpu: cpu_model(),
+            profile: if is_release {
+                "release".into()
+            } else {
+                "debug".into()
+            },
+        }
+    }
+
+    /// The toolchain and machine, used to point out when they change between runs.
+    fn machine(&self) -> String {
+        format!(
+            "{} on {} ({})",
+            self.rustc.as_deref().unwrap_or("unknown rustc"),
+            self.cpu.as_deref().unwrap_or("unknown CPU"),
+            self.profile
+        )
+    }
+}
+
+fn command_output(cmd: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(cmd).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let output = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Some(output).filter(|x| !x.is_empty())
+}
+
+fn git_commit() -> Option<String> {
+    let commit = command_output("git", &["rev-parse", "--short", "HEAD"])?;
+    let is_dirty =
+        command_output("git", &["status", "--porcelain", "--untracked-files=no"]).is_some();
+    Some(if is_dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+fn cpu_model() -> Option<String> {
+    if cfg!(target_os = "macos") {
+        return command_output("sysctl", &["-n", "machdep.cpu.brand_string"]);
+    }
+
+    if cfg!(target_os = "windows") {
+        return env::var("PROCESSOR_IDENTIFIER").ok();
+    }
+
+    fs::read_to_string("/proc/cpuinfo")
+        .ok()?
+        .lines()
+        .find(|line| line.starts_with("model name"))
+        .and_then(|line| line.split_once(':'))
+        .map(|(_, model)| model.trim().to_string())
+}
+
+/// The timing of a day in a single benchmark run.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    pub run: RunInfo,
+    pub timing: Timing,
+}
+
+/// Append-only log of benchmark runs.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct TimingsHistory {
+    pub data: Vec<HistoryEntry>,
+}
+
+impl TimingsHistory {
+    /// Dehydrate the history to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(HISTORY_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the history from a JSON file. If not present, returns an empty history.
+    ///
+    /// A file that cannot be read or parsed is an error rather than an empty history, which would overwrite the log
+    /// when it is stored again.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(HISTORY_FILE_PATH) {
+            Ok(json) => TimingsHistory::try_from(json),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(TimingsHistory::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Append the timings of a run. Days without any solved part are skipped.
+    pub fn append(&mut self, run: &RunInfo, timings: &Timings) {
+        for timing in &timings.data {
+            if timing.part_1.is_some() || timing.part_2.is_some() {
+                self.data.push(HistoryEntry {
+                    run: run.clone(),
+                    timing: timing.clone(),
+                });
+            }
+        }
+    }
+
+    /// All entries of a puzzle, oldest first.
+    #[must_use]
+    pub fn for_puzzle(&self, puzzle: PuzzleId) -> Vec<&HistoryEntry> {
+        let mut entries: Vec<&HistoryEntry> = self
+            .data
+            .iter()
+            .filter(|entry| entry.timing.puzzle == puzzle)
+            .collect();
+        entries.sort_by_key(|entry| entry.run.time);
+        entries
+    }
+
+    /// Print the trend of a puzzle's timings.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn print(&self, puzzle: PuzzleId) {
+        let entries = self.for_puzzle(puzzle);
+
+        println!("{ANSI_BOLD}History of {puzzle}{ANSI_RESET}");
+        println!("------");
+
+        if entries.is_empty() {
+            println!("{ANSI_ITALIC}No stored runs, use `cargo time <day> --store` to record one.{ANSI_RESET}");
+            return;
+        }
+
+        println!(
+            "{:<17} {:<14} {:<12} {:<12} {:<12} Change",
+            "Date", "Commit", "Part 1", "Part 2", "Total"
+        );
+
+        let mut previous: Option<&HistoryEntry> = None;
+
+        for entry in entries {
+            let machine = entry.run.machine();
+            if previous.is_none_or(|p| p.run.machine() != machine) {
+                println!("{ANSI_ITALIC}{machine}{ANSI_RESET}");
+            }
+
+            let timing = &entry.timing;
+            println!(
+                "{:<17} {:<14} {:<12} {:<12} {:<12} {}",
+                format_date(entry.run.time),
+                entry.run.commit.as_deref().unwrap_or("-"),
+                timing.part_1.as_deref().unwrap_or("-"),
+                timing.part_2.as_deref().unwrap_or("-"),
+                format!("{:.1?}", Duration::from_nanos(timing.total_nanos as u64)),
+                format_change(previous.map(|p| p.timing.total_nanos), timing.total_nanos)
+            );
+
+            previous = Some(entry);
+        }
+    }
+}
+
+/// Relative change of the total time compared to the previous run, e.g. `-95.0%`.
+fn format_change(previous: Option<f64>, current: f64) -> String {
+    match previous {
+        Some(previous) if previous > 0.0 => {
+            format!("{:+.1}%", (current - previous) / previous * 100.0)
+        }
+        _ => "-".into(),
+    }
+}
+
+/// Format seconds since the unix epoch as UTC date, e.g. `2023-12-17 08:12`.
+fn format_date(time: u64) -> String {
+    let days = time / 86_400;
+    let seconds = time % 86_400;
+
+    // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    #[allow(clippy::cast_possible_wrap)]
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&TimingsHistory> for JsonValue {
+    fn from(value: &TimingsHistory) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for TimingsHistory {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(TimingsHistory {
+            data: json_data
+                .iter()
+                .map(HistoryEntry::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let string = |x: &Option<String>| x.clone().map_or(JsonValue::Null, JsonValue::String);
+
+        map.insert("time".into(), JsonValue::Number(value.run.time as f64));
+        map.insert("commit".into(), string(&value.run.commit));
+        map.insert("rustc".into(), string(&value.run.rustc));
+        map.insert("cpu".into(), string(&value.run.cpu));
+        map.insert(
+            "profile".into(),
+            JsonValue::String(value.run.profile.clone()),
+        );
+        map.insert("timing".into(), JsonValue::from(&value.timing));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let time = json
+            .get("time")
+            .and_then(|v| v.get::<f64>().copied())
+            .map(|x| x as u64)
+            .ok_or("Expected entry.time to be a number.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected entry.{key} to be null or string."))
+                .map(|x| x.cloned())
+        };
+
+        let profile = string("profile")?.ok_or("Expected entry.profile to be a string.")?;
+
+        let timing = json
+            .get("timing")
+            .ok_or("Expected entry to have key `timing`.")
+            .map(Timing::try_from)??;
+
+        Ok(HistoryEntry {
+            run: RunInfo {
+                time,
+                commit: string("commit")?,
+                rustc: string("rustc")?,
+                cpu: string("cpu")?,
+                profile,
+            },
+            timing,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_change, format_date, RunInfo, TimingsHistory};
+    use crate::{
+        puzzle,
+        template::timings::{Timing, Timings},
+    };
+
+    fn timing(day: u8, total_nanos: f64) -> Timing {
+        Timing {
+            puzzle: crate::template::PuzzleId::new(
+                crate::year!(2023),
+                crate::template::Day::new(day).unwrap(),
+            ),
+            parse: None,
+            parse_stats: None,
+            part_1: Some("10ms".into()),
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_heap: None,
+            part_2_heap: None,
+            total_nanos,
+        }
+    }
+
+    fn run(time: u64) -> RunInfo {
+        RunInfo {
+            time,
+            commit: Some("1c112c1".into()),
+            rustc: Some("rustc 1.74.0".into()),
+            cpu: None,
+            profile: "release".into(),
+        }
+    }
+
+    #[test]
+    fn appends_runs() {
+        let mut history = TimingsHistory::default();
+        history.append(
+            &run(20),
+            &Timings {
+                data: vec![timing(17, 4e7)],
+            },
+        );
+        history.append(
+            &run(10),
+            &Timings {
+                data: vec![timing(17, 8e8), timing(1, 1e6)],
+            },
+        );
+
+        let mut unsolved = timing(2, 0.0);
+        unsolved.part_1 = None;
+        history.append(
+            &run(30),
+            &Timings {
+                data: vec![unsolved],
+            },
+        );
+
+        assert_eq!(history.data.len(), 3);
+        let entries = history.for_puzzle(puzzle!(2023, 17));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].run.time, 10);
+        assert_eq!(entries[1].timing.total_nanos, 4e7);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut history = TimingsHistory::default();
+        history.append(
+            &run(1_702_800_000),
+            &Timings {
+                data: vec![timing(17, 4e7)],
+            },
+        );
+
+        let json = tinyjson::JsonValue::from(&history).stringify().unwrap();
+        let parsed = TimingsHistory::try_from(json).unwrap();
+
+        assert_eq!(parsed.data.len(), 1);
+        assert_eq!(parsed.data[0].run, run(1_702_800_000));
+        assert_eq!(parsed.data[0].timing.puzzle, puzzle!(2023, 17));
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01 00:00");
+        assert_eq!(format_date(1_702_800_720), "2023-12-17 08:12");
+        assert_eq!(format_date(1_709_164_800), "2024-02-29 00:00");
+    }
+
+    #[test]
+    fn formats_changes() {
+        assert_eq!(format_change(None, 4e7), "-");
+        assert_eq!(format_change(Some(8e8), 4e7), "-95.0%");
+        assert_eq!(format_change(Some(1e6), 1.5e6), "+50.0%");
+    }
+}
@@ -0,0 +1,143 @@
+/// Compares competing implementations of a part, e.g. a naive and an optimised one.
+///
+/// Variants are registered with `solution!(2023, 18, variants = [1: part_one_flood_fill])`.
+/// `cargo solve <day> --variants` checks that all variants of a part agree on the answer and benches them side by side.
+use std::{env, io::Write, process, time::Duration};
+
+use crate::template::{
+    runner::{human_output, run_timed},
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// A named implementation of a part, returning its answer as string or the error it failed with.
+pub struct Variant<'a> {
+    pub part: u8,
+    pub name: &'static str,
+    pub func: &'a dyn Fn() -> Result<Option<String>, String>,
+}
+
+/// `true` when the solution is run with `--variants`.
+#[must_use]
+pub fn is_enabled() -> bool {
+    env::args().any(|x| x == "--variants")
+}
+
+/// Bench every variant and print them side by side, grouped by part.
+/// The first variant of a part is the baseline that the others are compared against.
+/// Exits with a non-zero status if the variants of a part do not agree on the answer.
+pub fn run(variants: &[Variant]) {
+    let mut parts: Vec<u8> = variants.iter().map(|v| v.part).collect();
+    parts.sort_unstable();
+    parts.dedup();
+
+    let mut disagreements = 0;
+
+    for (i, part) in parts.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Part {part}{ANSI_RESET}");
+        println!("------");
+
+        let mut baseline: Option<(Result<Option<String>, String>, Duration)> = None;
+
+        for variant in variants.iter().filter(|v| v.part == part) {
+            let (answer, stats, _) = run_timed(
+                |()| (variant.func)(),
+                (),
+                true,
+                |_| {
+                    let mut out = human_output();
+                    let _ = write!(out, "{:<24}", variant.name);
+                    let _ = out.flush();
+                },
+            );
+
+            let is_baseline = baseline.is_none();
+            let (expected, baseline_mean) =
+                baseline.get_or_insert_with(|| (answer.clone(), stats.mean));
+
+            let agrees = answer == *expected;
+            if !agrees {
+                disagreements += 1;
+            }
+
+            let mut out = human_output();
+            let _ = writeln!(
+                out,
+                "\r{:<24} {:<16} {:<28} {}{}",
+                variant.name,
+                format_answer(&answer),
+                format!("{:.1?} @ {} samples", stats.mean, stats.samples),
+                format_relative(stats.mean, *baseline_mean, is_baseline),
+                if agrees {
+                    String::new()
+                } else {
+                    format!(" {ANSI_BOLD}✖ answer differs{ANSI_RESET}")
+                }
+            );
+        }
+    }
+
+    println!();
+    if disagreements > 0 {
+        println!("{ANSI_BOLD}{disagreements} variant(s) disagree with the baseline.{ANSI_RESET}");
+        process::exit(1);
+    }
+
+    println!("{ANSI_ITALIC}All variants agree.{ANSI_RESET}");
+}
+
+fn format_answer(answer: &Result<Option<String>, String>) -> String {
+    match answer {
+        Ok(Some(answer)) if answer.contains('\n') => "▼".into(),
+        Ok(Some(answer)) => answer.clone(),
+        Ok(None) => "✖".into(),
+        Err(e) => format!("error: {e}"),
+    }
+}
+
+/// Speed of a variant relative to the baseline, e.g. `3.20x slower`.
+fn format_relative(mean: Duration, baseline: Duration, is_baseline: bool) -> String {
+    if is_baseline {
+        return "baseline".into();
+    }
+
+    if mean.is_zero() || baseline.is_zero() {
+        return "-".into();
+    }
+
+    let ratio = mean.as_secs_f64() / baseline.as_secs_f64();
+    if ratio >= 1.0 {
+        format!("{ratio:.2}x slower")
+    } else {
+        format!("{:.2}x faster", 1.0 / ratio)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_answer, format_relative};
+
+    #[test]
+    fn formats_relative_speed() {
+        let ms = Duration::from_millis;
+        assert_eq!(format_relative(ms(10), ms(10), true), "baseline");
+        assert_eq!(format_relative(ms(32), ms(10), false), "3.20x slower");
+        assert_eq!(format_relative(ms(5), ms(10), false), "2.00x faster");
+        assert_eq!(format_relative(ms(5), Duration::ZERO, false), "-");
+    }
+
+    #[test]
+    fn formats_answers() {
+        assert_eq!(format_answer(&Ok(Some("42".into()))), "42");
+        assert_eq!(format_answer(&Ok(Some("#.\n.#".into()))), "▼");
+        assert_eq!(format_answer(&Ok(None)), "✖");
+        assert_eq!(format_answer(&Err("no path".into())), "error: no path");
+    }
+}